@@ -20,6 +20,37 @@ pub fn shift_right_report_u32(a: u32, shift: u32) -> String {
     s
 }
 
+/// Like [`shift_right_report_u32`], for `a.rotate_left(n % 32)` (shown as `<<<`).
+pub fn rotate_left_report_u32(a: u32, n: u32) -> String {
+    let n = n % 32;
+    rotate_report_u32("Rotate left (u32)", "<<<", a, n, a.rotate_left(n))
+}
+
+/// Like [`shift_right_report_u32`], for `a.rotate_right(n % 32)` (shown as `>>>`).
+pub fn rotate_right_report_u32(a: u32, n: u32) -> String {
+    let n = n % 32;
+    rotate_report_u32("Rotate right (u32)", ">>>", a, n, a.rotate_right(n))
+}
+
+fn rotate_report_u32(title: &str, op: &str, a: u32, n: u32, r: u32) -> String {
+    let mut s = String::new();
+    s.push_str(&format!("{title}\n"));
+    s.push_str(&format!("{}\n\n", "=".repeat(title.len())));
+    s.push_str(&format_line("a", a));
+    s.push_str(&format!("n = {n}\n\n"));
+
+    s.push_str("Binary (32-bit)\n");
+    s.push_str("--------------\n");
+    s.push_str(&format!("a         = {}\n", bin32(a)));
+    s.push_str(&format!("a {op} {n:2}  = {}\n\n", bin32(r)));
+
+    s.push_str("Result\n");
+    s.push_str("------\n");
+    s.push_str(&format!("dec: {r}\n"));
+    s.push_str(&format!("hex: {}\n", hex(r)));
+    s
+}
+
 /// Returns true if the k-th bit of `a` is 1.
 /// `k=0` is the least significant bit.
 pub fn is_bit_set_u32(a: u32, k: u32) -> bool {
@@ -38,11 +69,123 @@ pub fn set_bit_u32(a: u32, k: u32) -> u32 {
     a | (1u32 << k)
 }
 
+/// Returns `a` with the k-th bit set to 0.
+/// `k=0` is the least significant bit.
+pub fn clear_bit_u32(a: u32, k: u32) -> u32 {
+    if k >= 32 {
+        return a;
+    }
+    a & !(1u32 << k)
+}
+
+/// Returns `a` with the k-th bit flipped.
+/// `k=0` is the least significant bit.
+pub fn toggle_bit_u32(a: u32, k: u32) -> u32 {
+    if k >= 32 {
+        return a;
+    }
+    a ^ (1u32 << k)
+}
+
+/// The `len` bits of `a` starting at bit `start`, shifted down to bit 0. The field is clamped
+/// to bit 31, so `start >= 32` or `len == 0` gives 0.
+/// Example: a=0xAABBCCDD, start=8, len=8 -> 0xCC.
+pub fn extract_bits_u32(a: u32, start: u32, len: u32) -> u32 {
+    if start >= 32 {
+        return 0;
+    }
+    (a >> start) & field_mask(start, len)
+}
+
+/// Returns `a` with the `len`-bit field at `start` replaced by the low bits of `value`; bits
+/// of `value` that don't fit are dropped. Clamped like [`extract_bits_u32`]: an empty field
+/// returns `a` unchanged.
+pub fn insert_bits_u32(a: u32, value: u32, start: u32, len: u32) -> u32 {
+    if start >= 32 {
+        return a;
+    }
+    let mask = field_mask(start, len);
+    (a & !(mask << start)) | ((value & mask) << start)
+}
+
 /// Hamming distance between two u32 values: number of differing bits.
 pub fn hamming_distance_u32(a: u32, b: u32) -> u32 {
     (a ^ b).count_ones()
 }
 
+/// Hamming distance between two equal-length byte strings (e.g. perceptual hashes).
+/// Errors if the lengths differ.
+pub fn hamming_distance_bytes(a: &[u8], b: &[u8]) -> Result<u32, &'static str> {
+    if a.len() != b.len() {
+        return Err("hamming_distance_bytes: inputs differ in length");
+    }
+    Ok(a.iter().zip(b).map(|(x, y)| (x ^ y).count_ones()).sum())
+}
+
+/// Report of the set bits of `a`: its grouped binary form, how many bits are set, and their
+/// indices (`k=0` is the LSB). See [`powers_of_two_u32`] for the matching values.
+pub fn popcount_report_u32(a: u32) -> String {
+    let indices: Vec<String> = (0u32..32).filter(|k| (a >> k) & 1 == 1).map(|k| k.to_string()).collect();
+
+    let mut s = String::new();
+    s.push_str("Popcount (u32)\n");
+    s.push_str("==============\n\n");
+    s.push_str(&format_line("a", a));
+    s.push('\n');
+
+    s.push_str("Binary (32-bit)\n");
+    s.push_str("--------------\n");
+    s.push_str(&format!("a = {}\n\n", bin32(a)));
+
+    s.push_str("Result\n");
+    s.push_str("------\n");
+    s.push_str(&format!("set bits: {}\n", a.count_ones()));
+    s.push_str(&format!("indices: [{}]\n", indices.join(", ")));
+    s
+}
+
+/// Number of consecutive set bits starting from the LSB. Example: 0b0111 -> 3.
+pub fn count_trailing_ones_u32(a: u32) -> u32 {
+    a.trailing_ones()
+}
+
+/// Number of consecutive set bits starting from the MSB. Example: 0xF000_0000 -> 4.
+pub fn count_leading_ones_u32(a: u32) -> u32 {
+    a.leading_ones()
+}
+
+/// Bit order reversed: bit k moves to bit 31-k. Example: 0b1 -> 0x8000_0000.
+pub fn reverse_bits_u32(a: u32) -> u32 {
+    a.reverse_bits()
+}
+
+/// Byte order reversed (little <-> big endian). Example: 0x1122_3344 -> 0x4433_2211.
+pub fn swap_bytes_u32(a: u32) -> u32 {
+    a.swap_bytes()
+}
+
+/// Report showing `a` and [`reverse_bits_u32`]`(a)` side by side in grouped binary.
+pub fn reverse_bits_report_u32(a: u32) -> String {
+    let r = a.reverse_bits();
+
+    let mut s = String::new();
+    s.push_str("Reverse bits (u32)\n");
+    s.push_str("==================\n\n");
+    s.push_str(&format_line("a", a));
+    s.push('\n');
+
+    s.push_str("Binary (32-bit)\n");
+    s.push_str("--------------\n");
+    s.push_str(&format!("a          = {}\n", bin32(a)));
+    s.push_str(&format!("reversed a = {}\n\n", bin32(r)));
+
+    s.push_str("Result\n");
+    s.push_str("------\n");
+    s.push_str(&format!("dec: {r}\n"));
+    s.push_str(&format!("hex: {}\n", hex(r)));
+    s
+}
+
 /// Returns all powers of two that sum to `a` (i.e. for each set bit k, includes 2^k).
 /// Example: a=13 -> [1,4,8].
 pub fn powers_of_two_u32(a: u32) -> Vec<u32> {
@@ -55,6 +198,187 @@ pub fn powers_of_two_u32(a: u32) -> Vec<u32> {
     out
 }
 
+/// For each bit position k (k=0 is the LSB), counts how many of `values` have bit k set.
+/// Example: [0b011, 0b110] -> [1, 2, 1, 0, ...].
+pub fn bit_column_counts(values: &[u32]) -> [u32; 32] {
+    let mut counts = [0u32; 32];
+    for &v in values {
+        for (k, c) in counts.iter_mut().enumerate() {
+            *c += (v >> k) & 1;
+        }
+    }
+    counts
+}
+
+/// Maps a signed value to unsigned so small magnitudes stay small:
+/// 0 -> 0, -1 -> 1, 1 -> 2, -2 -> 3, ...
+pub fn zigzag_encode_i32(v: i32) -> u32 {
+    ((v << 1) ^ (v >> 31)) as u32
+}
+
+/// Inverse of [`zigzag_encode_i32`].
+pub fn zigzag_decode_u32(v: u32) -> i32 {
+    ((v >> 1) as i32) ^ -((v & 1) as i32)
+}
+
+/// Smallest power of two `>= a`; 0 maps to 1 and values above `2^31` saturate to `2^31`.
+pub fn next_power_of_two_u32(a: u32) -> u32 {
+    a.checked_next_power_of_two().unwrap_or(1 << 31)
+}
+
+/// IEEE 754 binary16 bits for `v`, rounding to nearest even. Overflow becomes infinity, NaN
+/// stays NaN, and magnitudes below the smallest subnormal (~6e-8) flush to signed zero.
+pub fn f32_to_f16_bits(v: f32) -> u16 {
+    let x = v.to_bits();
+    let sign = ((x >> 16) & 0x8000) as u16;
+    let exp = ((x >> 23) & 0xFF) as i32;
+    let man = x & 0x7F_FFFF;
+    if exp == 0xFF {
+        return sign | 0x7C00 | if man != 0 { 0x200 } else { 0 };
+    }
+    let e = exp - 127 + 15;
+    if e >= 0x1F {
+        return sign | 0x7C00;
+    }
+    // Subnormal halves keep the implicit bit; `shift` drops the bits that don't fit.
+    let (m, shift, base) = if e <= 0 {
+        if e < -10 {
+            return sign;
+        }
+        (man | 0x80_0000, (14 - e) as u32, 0)
+    } else {
+        (man, 13, (e as u32) << 10)
+    };
+    let halfway = 1 << (shift - 1);
+    let rem = m & ((1 << shift) - 1);
+    let mut r = base | (m >> shift);
+    if rem > halfway || (rem == halfway && r & 1 == 1) {
+        // A carry out of the mantissa correctly bumps the exponent (up to infinity).
+        r += 1;
+    }
+    sign | r as u16
+}
+
+/// Inverse of [`f32_to_f16_bits`]; exact, since every binary16 value is representable as f32.
+pub fn f16_bits_to_f32(h: u16) -> f32 {
+    let sign = ((h & 0x8000) as u32) << 16;
+    let exp = ((h >> 10) & 0x1F) as u32;
+    let man = (h & 0x3FF) as u32;
+    let bits = match exp {
+        0 => {
+            // Zero or subnormal: man * 2^-24.
+            let v = man as f32 * (1.0 / (1 << 24) as f32);
+            return if sign != 0 { -v } else { v };
+        }
+        0x1F => sign | 0x7F80_0000 | (man << 13),
+        _ => sign | ((exp + 112) << 23) | (man << 13),
+    };
+    f32::from_bits(bits)
+}
+
+/// 30-bit Morton (Z-order) code interleaving the low 10 bits of `x`, `y`, `z`, with `x` in the
+/// lowest bit: `...z1y1x1z0y0x0`. Higher input bits are ignored.
+pub fn morton_encode_3d(x: u32, y: u32, z: u32) -> u32 {
+    spread_bits3(x) | (spread_bits3(y) << 1) | (spread_bits3(z) << 2)
+}
+
+/// Inverse of [`morton_encode_3d`]: the `(x, y, z)` packed in the low 30 bits of `code`, each
+/// below 1024. Bits 30 and 31 are ignored.
+pub fn morton_decode_3d(code: u32) -> (u32, u32, u32) {
+    (compact_bits3(code), compact_bits3(code >> 1), compact_bits3(code >> 2))
+}
+
+/// 64-bit counterpart of [`shift_right_report_u32`].
+pub fn shift_right_report_u64(a: u64, shift: u32) -> String {
+    let shift = shift % 64;
+    let r = a >> shift;
+
+    let mut s = String::new();
+    s.push_str("Right shift (u64)\n");
+    s.push_str("=================\n\n");
+    s.push_str(&format!("a = {a}  ({})\n", hex64(a)));
+    s.push_str(&format!("shift = {shift}\n\n"));
+
+    s.push_str("Binary (64-bit)\n");
+    s.push_str("--------------\n");
+    s.push_str(&format!("a        = {}\n", bin64(a)));
+    s.push_str(&format!("a >> {shift:2} = {}\n\n", bin64(r)));
+
+    s.push_str("Result\n");
+    s.push_str("------\n");
+    s.push_str(&format!("dec: {r}\n"));
+    s.push_str(&format!("hex: {}\n", hex64(r)));
+    s
+}
+
+/// Returns true if the k-th bit of `a` is 1.
+/// `k=0` is the least significant bit.
+pub fn is_bit_set_u64(a: u64, k: u32) -> bool {
+    if k >= 64 {
+        return false;
+    }
+    (a & (1u64 << k)) != 0
+}
+
+/// Returns `a` with the k-th bit set to 1.
+/// `k=0` is the least significant bit.
+pub fn set_bit_u64(a: u64, k: u32) -> u64 {
+    if k >= 64 {
+        return a;
+    }
+    a | (1u64 << k)
+}
+
+/// Returns `a` with the k-th bit set to 0.
+/// `k=0` is the least significant bit.
+pub fn clear_bit_u64(a: u64, k: u32) -> u64 {
+    if k >= 64 {
+        return a;
+    }
+    a & !(1u64 << k)
+}
+
+/// Hamming distance between two u64 values: number of differing bits.
+pub fn hamming_distance_u64(a: u64, b: u64) -> u32 {
+    (a ^ b).count_ones()
+}
+
+/// Returns all powers of two that sum to `a` (i.e. for each set bit k, includes 2^k).
+/// Example: a=13 -> [1,4,8].
+pub fn powers_of_two_u64(a: u64) -> Vec<u64> {
+    let mut out = Vec::new();
+    for k in 0..64 {
+        if ((a >> k) & 1) == 1 {
+            out.push(1u64 << k);
+        }
+    }
+    out
+}
+
+/// Moves bit k of the low 10 bits of `v` to bit 3k.
+fn spread_bits3(v: u32) -> u32 {
+    let mut v = v & 0x3FF;
+    v = (v | (v << 16)) & 0x0300_00FF;
+    v = (v | (v << 8)) & 0x0300_F00F;
+    v = (v | (v << 4)) & 0x030C_30C3;
+    (v | (v << 2)) & 0x0924_9249
+}
+
+/// Inverse of [`spread_bits3`]: gathers bits 0, 3, 6, ..., 27 of `v` into the low 10 bits.
+fn compact_bits3(v: u32) -> u32 {
+    let mut v = v & 0x0924_9249;
+    v = (v | (v >> 2)) & 0x030C_30C3;
+    v = (v | (v >> 4)) & 0x0300_F00F;
+    v = (v | (v >> 8)) & 0x0300_00FF;
+    (v | (v >> 16)) & 0x3FF
+}
+
+/// Low-bit mask for a field at `start < 32` of `len` bits, clamped to bit 31.
+fn field_mask(start: u32, len: u32) -> u32 {
+    let len = len.min(32 - start);
+    if len == 32 { u32::MAX } else { (1u32 << len) - 1 }
+}
+
 fn format_line(name: &str, v: u32) -> String {
     format!("{name} = {v}  ({})\n", hex(v))
 }
@@ -63,6 +387,10 @@ fn hex(v: u32) -> String {
     format!("0x{v:08X}")
 }
 
+fn hex64(v: u64) -> String {
+    format!("0x{v:016X}")
+}
+
 fn bin32(v: u32) -> String {
     // Group by 4 bits: 0000_0000_...
     let mut out = String::with_capacity(32 + 7);
@@ -76,4 +404,15 @@ fn bin32(v: u32) -> String {
     out
 }
 
-
+fn bin64(v: u64) -> String {
+    // Group by 4 bits, like `bin32`.
+    let mut out = String::with_capacity(64 + 15);
+    for i in (0..64).rev() {
+        let bit = (v >> i) & 1;
+        out.push(if bit == 1 { '1' } else { '0' });
+        if i % 4 == 0 && i != 0 {
+            out.push('_');
+        }
+    }
+    out
+}
@@ -7,6 +7,16 @@ pub fn shift_right_report_u32(a: u32, shift: u32) -> String {
     bitops_core::shift_right_report_u32(a, shift)
 }
 
+#[wasm_bindgen]
+pub fn rotate_left_report_u32(a: u32, n: u32) -> String {
+    bitops_core::rotate_left_report_u32(a, n)
+}
+
+#[wasm_bindgen]
+pub fn rotate_right_report_u32(a: u32, n: u32) -> String {
+    bitops_core::rotate_right_report_u32(a, n)
+}
+
 #[wasm_bindgen]
 pub fn is_bit_set_u32(a: u32, k: u32) -> bool {
     bitops_core::is_bit_set_u32(a, k)
@@ -17,11 +27,67 @@ pub fn set_bit_u32(a: u32, k: u32) -> u32 {
     bitops_core::set_bit_u32(a, k)
 }
 
+#[wasm_bindgen]
+pub fn clear_bit_u32(a: u32, k: u32) -> u32 {
+    bitops_core::clear_bit_u32(a, k)
+}
+
+#[wasm_bindgen]
+pub fn toggle_bit_u32(a: u32, k: u32) -> u32 {
+    bitops_core::toggle_bit_u32(a, k)
+}
+
+#[wasm_bindgen]
+pub fn extract_bits_u32(a: u32, start: u32, len: u32) -> u32 {
+    bitops_core::extract_bits_u32(a, start, len)
+}
+
+#[wasm_bindgen]
+pub fn insert_bits_u32(a: u32, value: u32, start: u32, len: u32) -> u32 {
+    bitops_core::insert_bits_u32(a, value, start, len)
+}
+
 #[wasm_bindgen]
 pub fn hamming_distance_u32(a: u32, b: u32) -> u32 {
     bitops_core::hamming_distance_u32(a, b)
 }
 
+/// Throws if `a` and `b` differ in length.
+#[wasm_bindgen]
+pub fn hamming_distance_bytes(a: &[u8], b: &[u8]) -> Result<u32, JsValue> {
+    bitops_core::hamming_distance_bytes(a, b).map_err(JsValue::from_str)
+}
+
+#[wasm_bindgen]
+pub fn popcount_report_u32(a: u32) -> String {
+    bitops_core::popcount_report_u32(a)
+}
+
+#[wasm_bindgen]
+pub fn count_trailing_ones_u32(a: u32) -> u32 {
+    bitops_core::count_trailing_ones_u32(a)
+}
+
+#[wasm_bindgen]
+pub fn count_leading_ones_u32(a: u32) -> u32 {
+    bitops_core::count_leading_ones_u32(a)
+}
+
+#[wasm_bindgen]
+pub fn reverse_bits_u32(a: u32) -> u32 {
+    bitops_core::reverse_bits_u32(a)
+}
+
+#[wasm_bindgen]
+pub fn swap_bytes_u32(a: u32) -> u32 {
+    bitops_core::swap_bytes_u32(a)
+}
+
+#[wasm_bindgen]
+pub fn reverse_bits_report_u32(a: u32) -> String {
+    bitops_core::reverse_bits_report_u32(a)
+}
+
 #[wasm_bindgen]
 pub fn powers_of_two_u32(a: u32) -> js_sys::Array {
     let arr = js_sys::Array::new();
@@ -31,4 +97,79 @@ pub fn powers_of_two_u32(a: u32) -> js_sys::Array {
     arr
 }
 
+#[wasm_bindgen]
+pub fn bit_column_counts(values: &[u32]) -> js_sys::Uint32Array {
+    js_sys::Uint32Array::from(&bitops_core::bit_column_counts(values)[..])
+}
 
+#[wasm_bindgen]
+pub fn zigzag_encode_i32(v: i32) -> u32 {
+    bitops_core::zigzag_encode_i32(v)
+}
+
+#[wasm_bindgen]
+pub fn zigzag_decode_u32(v: u32) -> i32 {
+    bitops_core::zigzag_decode_u32(v)
+}
+
+#[wasm_bindgen]
+pub fn next_power_of_two_u32(a: u32) -> u32 {
+    bitops_core::next_power_of_two_u32(a)
+}
+
+#[wasm_bindgen]
+pub fn f32_to_f16_bits(v: f32) -> u16 {
+    bitops_core::f32_to_f16_bits(v)
+}
+
+#[wasm_bindgen]
+pub fn f16_bits_to_f32(h: u16) -> f32 {
+    bitops_core::f16_bits_to_f32(h)
+}
+
+#[wasm_bindgen]
+pub fn morton_encode_3d(x: u32, y: u32, z: u32) -> u32 {
+    bitops_core::morton_encode_3d(x, y, z)
+}
+
+/// `[x, y, z]` of a 30-bit Morton code.
+#[wasm_bindgen]
+pub fn morton_decode_3d(code: u32) -> js_sys::Array {
+    let (x, y, z) = bitops_core::morton_decode_3d(code);
+    [x, y, z].iter().map(|&v| JsValue::from(v)).collect()
+}
+
+#[wasm_bindgen]
+pub fn shift_right_report_u64(a: u64, shift: u32) -> String {
+    bitops_core::shift_right_report_u64(a, shift)
+}
+
+#[wasm_bindgen]
+pub fn is_bit_set_u64(a: u64, k: u32) -> bool {
+    bitops_core::is_bit_set_u64(a, k)
+}
+
+#[wasm_bindgen]
+pub fn set_bit_u64(a: u64, k: u32) -> u64 {
+    bitops_core::set_bit_u64(a, k)
+}
+
+#[wasm_bindgen]
+pub fn clear_bit_u64(a: u64, k: u32) -> u64 {
+    bitops_core::clear_bit_u64(a, k)
+}
+
+#[wasm_bindgen]
+pub fn hamming_distance_u64(a: u64, b: u64) -> u32 {
+    bitops_core::hamming_distance_u64(a, b)
+}
+
+/// Powers of two as `BigInt`s.
+#[wasm_bindgen]
+pub fn powers_of_two_u64(a: u64) -> js_sys::Array {
+    let arr = js_sys::Array::new();
+    for v in bitops_core::powers_of_two_u64(a) {
+        arr.push(&JsValue::from(v));
+    }
+    arr
+}
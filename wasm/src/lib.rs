@@ -1,5 +1,8 @@
 pub mod ply_splat_core;
 pub mod bitops_core;
+pub mod splat_io_core;
+pub mod splat_ops_core;
+pub mod random_core;
 
 #[cfg(target_arch = "wasm32")]
 mod ply_splat_wasm;
@@ -7,30 +10,186 @@ mod ply_splat_wasm;
 #[cfg(target_arch = "wasm32")]
 mod bitops_wasm;
 
+#[cfg(target_arch = "wasm32")]
+mod splat_io_wasm;
+
+#[cfg(target_arch = "wasm32")]
+mod splat_ops_wasm;
+
 pub use ply_splat_core::{
-    parse_splat_ply_core, parse_splat_ply_core_with_opts, PlyError, PlyFormat, SplatPlyBuffersCore,
+    ascii_column_map, ignored_properties, ignored_properties_with_padding, output_layout,
+    parse_bbox_only, parse_ply_header, parse_splat_ply_core, parse_splat_ply_core_debug,
+    parse_splat_ply_core_downsampled, parse_splat_ply_core_gz, parse_splat_ply_core_subsampled,
+    parse_splat_ply_core_traced, parse_splat_ply_core_verbose, parse_splat_ply_core_with_options,
+    parse_splat_ply_core_with_opts, parse_splat_ply_core_with_progress,
+    parse_splat_ply_core_with_schema, parse_splat_ply_core_with_transform, parse_splat_ply_reader,
+    probe_splat_ply_core, sh_rest_len, ExporterProfile, FieldFlags, LogBase, NanPolicy, OpacityMode,
+    OutputLayout, ParseDiagnostics, ParseOptionsCore, PlyElement, PlyError, PlyErrorKind, PlyFormat,
+    PlyHeaderBuilder, PlyHeaderInfo, PlyProperty, PlyScalarType, QuatLayout, ReorderMode, ScaleMode,
+    SplatBuilder, SplatPlyBuffersCore, SplatProbeCore, SplatSchema, UnitHint, VertexLayout,
+    INTERLEAVED_STRIDE,
 };
 
+pub use splat_io_core::{
+    append_splats_to_ply, convert_ply_to_binary_le, decode_base64, decode_positions_delta,
+    deserialize_buffers, encode_positions_delta, export_gltf, generate_test_ply,
+    parse_splat_file_core, parse_splat_file_core_with_options, parse_splat_ply_base64_core,
+    recommend_output_format, serialize_buffers, to_webgl_splat_texture, write_splat,
+    write_splat_ply_ascii, write_subset_ply, OutputFormat, PlyWriteLayout, SplatFileOptions,
+    CACHE_FORMAT_VERSION, CACHE_MAGIC, GLTF_BUFFER_URI, GLTF_SPLAT_EXTENSION, SPLAT_RECORD_BYTES,
+    WEBGL_SPLAT_TEXTURE_WIDTH,
+};
+pub use splat_ops_core::{
+    camera_distances, clamp_anisotropy, colorize_by_depth, covariance_eigen, covariance_from_eigen,
+    covariance_from_half, covariance_from_i16, covariance_to_2d_ortho, covariance_to_half,
+    covariance_to_i16, covariance_to_scale_quat, dominant_color, estimate_overdraw, fuse,
+    importance_scores, interpolate_scenes, keep_most_important, median_scale, morton_order,
+    pick_ray, project_covariance_2d, remove_degenerate, scale_stats, sh_to_texture_layout,
+    sort_indices_by_depth, splat_importance, splat_normals, split_opacity, view_depths,
+    voxel_downsample, Axis, Colormap, ScaleStats, PICK_SIGMA,
+};
+
+pub use random_core::SplitMix64;
+
 #[cfg(target_arch = "wasm32")]
-pub use ply_splat_wasm::{parse_splat_ply, parse_splat_ply_with_opts, SplatPlyBuffers};
+pub use ply_splat_wasm::{
+    parse_ply_metadata, parse_splat_ply, parse_splat_ply_copied, parse_splat_ply_debug,
+    parse_splat_ply_downsampled, parse_splat_ply_gz, parse_splat_ply_subsampled,
+    parse_splat_ply_traced, parse_splat_ply_with_center_f64, parse_splat_ply_verbose,
+    parse_splat_ply_with_max_splats, parse_splat_ply_with_morton_order,
+    parse_splat_ply_with_options, parse_splat_ply_with_opts, parse_splat_ply_with_progress,
+    parse_splat_ply_with_quat_layout, parse_splat_ply_with_raw, parse_splat_ply_with_transform,
+    probe_splat_ply, ParseOptions, PlyMetadata, SplatPlyBuffers, SplatProbe,
+};
+
+#[cfg(target_arch = "wasm32")]
+pub use splat_io_wasm::{deserialize_splat_cache, parse_splat_file, parse_splat_ply_base64};
 
 pub use bitops_core::shift_right_report_u32 as shift_right_report_u32_core;
+pub use bitops_core::rotate_left_report_u32 as rotate_left_report_u32_core;
+pub use bitops_core::rotate_right_report_u32 as rotate_right_report_u32_core;
 pub use bitops_core::is_bit_set_u32 as is_bit_set_u32_core;
 pub use bitops_core::set_bit_u32 as set_bit_u32_core;
+pub use bitops_core::clear_bit_u32 as clear_bit_u32_core;
+pub use bitops_core::toggle_bit_u32 as toggle_bit_u32_core;
+pub use bitops_core::extract_bits_u32 as extract_bits_u32_core;
+pub use bitops_core::insert_bits_u32 as insert_bits_u32_core;
 pub use bitops_core::hamming_distance_u32 as hamming_distance_u32_core;
+pub use bitops_core::hamming_distance_bytes as hamming_distance_bytes_core;
+pub use bitops_core::popcount_report_u32 as popcount_report_u32_core;
+pub use bitops_core::count_trailing_ones_u32 as count_trailing_ones_u32_core;
+pub use bitops_core::count_leading_ones_u32 as count_leading_ones_u32_core;
+pub use bitops_core::reverse_bits_u32 as reverse_bits_u32_core;
+pub use bitops_core::swap_bytes_u32 as swap_bytes_u32_core;
+pub use bitops_core::reverse_bits_report_u32 as reverse_bits_report_u32_core;
 pub use bitops_core::powers_of_two_u32 as powers_of_two_u32_core;
+pub use bitops_core::bit_column_counts as bit_column_counts_core;
+pub use bitops_core::zigzag_encode_i32 as zigzag_encode_i32_core;
+pub use bitops_core::zigzag_decode_u32 as zigzag_decode_u32_core;
+pub use bitops_core::next_power_of_two_u32 as next_power_of_two_u32_core;
+pub use bitops_core::f32_to_f16_bits as f32_to_f16_bits_core;
+pub use bitops_core::f16_bits_to_f32 as f16_bits_to_f32_core;
+pub use bitops_core::morton_encode_3d as morton_encode_3d_core;
+pub use bitops_core::morton_decode_3d as morton_decode_3d_core;
+pub use bitops_core::shift_right_report_u64 as shift_right_report_u64_core;
+pub use bitops_core::is_bit_set_u64 as is_bit_set_u64_core;
+pub use bitops_core::set_bit_u64 as set_bit_u64_core;
+pub use bitops_core::clear_bit_u64 as clear_bit_u64_core;
+pub use bitops_core::hamming_distance_u64 as hamming_distance_u64_core;
+pub use bitops_core::powers_of_two_u64 as powers_of_two_u64_core;
 
 #[cfg(target_arch = "wasm32")]
 pub use bitops_wasm::shift_right_report_u32;
 
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::rotate_left_report_u32;
+
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::rotate_right_report_u32;
+
 #[cfg(target_arch = "wasm32")]
 pub use bitops_wasm::is_bit_set_u32;
 
 #[cfg(target_arch = "wasm32")]
 pub use bitops_wasm::set_bit_u32;
 
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::clear_bit_u32;
+
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::toggle_bit_u32;
+
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::extract_bits_u32;
+
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::insert_bits_u32;
+
 #[cfg(target_arch = "wasm32")]
 pub use bitops_wasm::hamming_distance_u32;
 
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::hamming_distance_bytes;
+
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::popcount_report_u32;
+
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::count_trailing_ones_u32;
+
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::count_leading_ones_u32;
+
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::reverse_bits_u32;
+
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::swap_bytes_u32;
+
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::reverse_bits_report_u32;
+
 #[cfg(target_arch = "wasm32")]
 pub use bitops_wasm::powers_of_two_u32;
+
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::bit_column_counts;
+
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::zigzag_encode_i32;
+
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::zigzag_decode_u32;
+
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::next_power_of_two_u32;
+
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::f32_to_f16_bits;
+
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::f16_bits_to_f32;
+
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::morton_encode_3d;
+
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::morton_decode_3d;
+
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::shift_right_report_u64;
+
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::is_bit_set_u64;
+
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::set_bit_u64;
+
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::clear_bit_u64;
+
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::hamming_distance_u64;
+
+#[cfg(target_arch = "wasm32")]
+pub use bitops_wasm::powers_of_two_u64;
@@ -1,5 +1,11 @@
 use std::collections::HashMap;
 
+use crate::bitops_core::{is_bit_set_u32, set_bit_u32};
+use crate::random_core::SplitMix64;
+use crate::splat_ops_core::{
+    covariance_det, covariance_to_scale_quat, keep_most_important, morton_order, scale_stats, voxel_downsample,
+};
+
 #[derive(Debug, Clone)]
 pub struct SplatPlyBuffersCore {
     pub count: u32,
@@ -9,6 +15,766 @@ pub struct SplatPlyBuffersCore {
     pub rgba: Box<[u32]>,       // N
     pub bbox_min: [f32; 3],
     pub bbox_max: [f32; 3],
+    /// Bounding sphere of the centers, kept in step with the bbox by [`Self::recompute_bounds`]:
+    /// centered on the bbox center, with the radius reaching the farthest center (a second
+    /// pass over the centers). Cheap rather than minimal: the radius is at most √3 times the
+    /// smallest enclosing sphere's. An empty scene has the origin and radius 0.
+    pub bsphere_center: [f32; 3],
+    pub bsphere_radius: f32,
+    /// Opacity-weighted mean of `center`, `Σ αᵢ·cᵢ / Σ αᵢ` with `α` the rgba alpha, e.g. as a
+    /// camera target. The plain mean if every alpha is 0; the origin for an empty scene.
+    pub centroid: [f32; 3],
+    /// Full-precision centers (3N), only when [`ParseOptionsCore::center_f64`] was set.
+    pub center_f64: Option<Box<[f64]>>,
+    /// Higher-order SH coefficients (`f_rest_*`, DC excluded), [`sh_rest_len`]`(sh_degree)`
+    /// per splat. Each splat's run is channel-major as in the INRIA files: all red
+    /// coefficients, then green, then blue. Empty when `sh_degree` is 0.
+    pub sh: Box<[f32]>,
+    /// SH degree (0–3) of `sh`.
+    pub sh_degree: u32,
+    /// Linear per-splat scales (3N) as the file stores them (before any Mip filter), only when
+    /// [`ParseOptionsCore::keep_raw`] was set and the file has scale + rotation properties.
+    /// Empty otherwise. Edits that reshape covariances in ways a scale + rotation can't follow
+    /// (e.g. [`Self::transform`]) clear it together with `quats`.
+    pub scales: Box<[f32]>,
+    /// Unit per-splat rotations (4N, `[x, y, z, w]`) matching `scales`; empty with it.
+    pub quats: Box<[f32]>,
+    /// PLY element the splats were read from (usually `vertex`; see
+    /// [`ParseOptionsCore::element_name`]). Empty for scenes not decoded from a PLY, such as
+    /// `.splat` files and restored caches.
+    pub element_name: String,
+    /// The header marks the file antialiased (see [`PlyHeaderInfo::antialiased`]): the trainer
+    /// baked its low-pass filter into opacity and covariance, so the renderer should skip its
+    /// own blur compensation.
+    pub antialiased: bool,
+    /// How the file stored its scales, as the decoder resolved it ([`ScaleMode::Log`] or
+    /// [`ScaleMode::Linear`], never `Auto`). `None` when the covariances didn't come from scale
+    /// properties (matrix covariances, restored caches) or for merges of scenes that disagree.
+    pub scale_mode: Option<ScaleMode>,
+}
+
+/// Builds a scene one splat at a time, growing plain `Vec`s so each push is amortized O(1);
+/// [`Self::finish`] computes the bounds once at the end.
+#[derive(Debug, Clone)]
+pub struct SplatBuilder {
+    /// Format, SH degree and the other per-scene fields; its buffers live in the `Vec`s below
+    /// until [`Self::finish`].
+    scene: SplatPlyBuffersCore,
+    center: Vec<f32>,
+    covariance: Vec<f32>,
+    rgba: Vec<u32>,
+    center_f64: Option<Vec<f64>>,
+    sh: Vec<f32>,
+    scales: Vec<f32>,
+    quats: Vec<f32>,
+}
+
+impl Default for SplatBuilder {
+    fn default() -> Self {
+        SplatBuilder::from_scene(SplatPlyBuffersCore::empty())
+    }
+}
+
+impl SplatBuilder {
+    /// A builder for a new scene, like [`SplatPlyBuffersCore::empty`].
+    pub fn new() -> SplatBuilder {
+        SplatBuilder::default()
+    }
+
+    /// Like [`Self::new`], with room for `count` splats.
+    pub fn with_capacity(count: usize) -> SplatBuilder {
+        let mut builder = SplatBuilder::new();
+        builder.center.reserve(count * 3);
+        builder.covariance.reserve(count * 6);
+        builder.rgba.reserve(count);
+        builder
+    }
+
+    /// Continues `scene`: its buffers are taken over without copying and pushed splats are
+    /// appended after its own, keeping its SH degree, `center_f64` and `scales`/`quats`.
+    pub fn from_scene(mut scene: SplatPlyBuffersCore) -> SplatBuilder {
+        SplatBuilder {
+            center: std::mem::take(&mut scene.center).into_vec(),
+            covariance: std::mem::take(&mut scene.covariance).into_vec(),
+            rgba: std::mem::take(&mut scene.rgba).into_vec(),
+            center_f64: scene.center_f64.take().map(Vec::from),
+            sh: std::mem::take(&mut scene.sh).into_vec(),
+            scales: std::mem::take(&mut scene.scales).into_vec(),
+            quats: std::mem::take(&mut scene.quats).into_vec(),
+            scene,
+        }
+    }
+
+    /// Number of splats so far.
+    pub fn len(&self) -> usize {
+        self.rgba.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rgba.is_empty()
+    }
+
+    /// Appends one splat. Its higher-order SH coefficients are zero (pure DC color), and if the
+    /// scene keeps `scales`/`quats` they are recovered from `covariance`.
+    pub fn push_splat(&mut self, center: [f32; 3], covariance: [f32; 6], rgba: u32) {
+        self.center.extend_from_slice(&center);
+        self.covariance.extend_from_slice(&covariance);
+        self.rgba.push(rgba);
+        if let Some(c) = self.center_f64.as_mut() {
+            c.extend(center.map(|v| v as f64));
+        }
+        let sh_len = sh_rest_len(self.scene.sh_degree);
+        self.sh.resize(self.sh.len() + sh_len, 0.0);
+        if !self.scales.is_empty() {
+            let (scale, quat) = covariance_to_scale_quat(&covariance);
+            self.scales.extend_from_slice(&scale);
+            self.quats.extend_from_slice(&quat);
+        }
+    }
+
+    /// The finished scene, with its bbox, bounding sphere and centroid from
+    /// [`SplatPlyBuffersCore::recompute_bounds`].
+    pub fn finish(self) -> SplatPlyBuffersCore {
+        let mut out = SplatPlyBuffersCore {
+            count: self.rgba.len() as u32,
+            center: self.center.into_boxed_slice(),
+            covariance: self.covariance.into_boxed_slice(),
+            rgba: self.rgba.into_boxed_slice(),
+            center_f64: self.center_f64.map(Vec::into_boxed_slice),
+            sh: self.sh.into_boxed_slice(),
+            scales: self.scales.into_boxed_slice(),
+            quats: self.quats.into_boxed_slice(),
+            ..self.scene
+        };
+        out.recompute_bounds();
+        out
+    }
+}
+
+/// Bytes per splat of [`SplatPlyBuffersCore::interleaved`]: 3 center + 6 covariance f32 and
+/// one packed rgba u32.
+pub const INTERLEAVED_STRIDE: usize = 40;
+
+/// Number of higher-order SH coefficients per splat for `degree`: `3 · ((degree + 1)² − 1)`,
+/// i.e. 0, 9, 24 or 45.
+pub fn sh_rest_len(degree: u32) -> usize {
+    3 * ((degree as usize + 1).pow(2) - 1)
+}
+
+/// Bitmask of the output buffers a [`SplatPlyBuffersCore`] has populated.
+/// The associated constants are bit indices (bit k set = buffer present).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FieldFlags(pub u32);
+
+impl FieldFlags {
+    /// `center` (3N f32).
+    pub const CENTER: u32 = 0;
+    /// `covariance` (6N f32).
+    pub const COVARIANCE: u32 = 1;
+    /// `rgba` (N u32).
+    pub const RGBA: u32 = 2;
+    /// `center_f64` (3N f64).
+    pub const CENTER_F64: u32 = 3;
+    /// `sh` (higher-order SH coefficients).
+    pub const SH: u32 = 4;
+    /// `scales` (3N f32) and `quats` (4N f32).
+    pub const SCALE_QUAT: u32 = 5;
+
+    pub fn with(self, bit: u32) -> Self {
+        FieldFlags(set_bit_u32(self.0, bit))
+    }
+
+    pub fn has(self, bit: u32) -> bool {
+        is_bit_set_u32(self.0, bit)
+    }
+}
+
+impl SplatPlyBuffersCore {
+    /// A scene with no splats, tagged `binary_little_endian`. The bbox and bounding sphere are
+    /// the empty-scene ones from [`Self::recompute_bounds`] (`min = +inf`, `max = -inf`), so
+    /// growing the bbox with `min`/`max` just works.
+    pub fn empty() -> SplatPlyBuffersCore {
+        SplatPlyBuffersCore {
+            count: 0,
+            format: PlyFormat::BinaryLittleEndian,
+            center: Box::new([]),
+            covariance: Box::new([]),
+            rgba: Box::new([]),
+            bbox_min: [f32::INFINITY; 3],
+            bbox_max: [f32::NEG_INFINITY; 3],
+            bsphere_center: [0.0; 3],
+            bsphere_radius: 0.0,
+            centroid: [0.0; 3],
+            center_f64: None,
+            sh: Box::new([]),
+            sh_degree: 0,
+            scales: Box::new([]),
+            quats: Box::new([]),
+            element_name: String::new(),
+            antialiased: false,
+            scale_mode: None,
+        }
+    }
+
+    /// Center, covariance and packed rgba of splat `index` (the inverse of
+    /// [`SplatBuilder::push_splat`]), or `None` if `index` is out of range.
+    pub fn splat(&self, index: usize) -> Option<([f32; 3], [f32; 6], u32)> {
+        if index >= self.count as usize {
+            return None;
+        }
+        let center = self.center[index * 3..index * 3 + 3].try_into().ok()?;
+        let covariance = self.covariance[index * 6..index * 6 + 6].try_into().ok()?;
+        Some((center, covariance, self.rgba[index]))
+    }
+
+    /// All splats packed into one vertex buffer for a single GPU upload, [`INTERLEAVED_STRIDE`]
+    /// bytes per splat, little-endian:
+    ///
+    /// | bytes  | attribute                                          |
+    /// |--------|----------------------------------------------------|
+    /// | 0–11   | center `x, y, z` (3 × f32)                         |
+    /// | 12–35  | covariance `m11, m12, m13, m22, m23, m33` (6 × f32) |
+    /// | 36–39  | packed rgba (u32, r in the low byte)               |
+    pub fn interleaved(&self) -> Box<[u8]> {
+        let mut out = Vec::with_capacity(self.count as usize * INTERLEAVED_STRIDE);
+        let splats = self.center.chunks_exact(3).zip(self.covariance.chunks_exact(6)).zip(&self.rgba[..]);
+        for ((center, cov), rgba) in splats {
+            for v in center.iter().chain(cov) {
+                out.extend_from_slice(&v.to_le_bytes());
+            }
+            out.extend_from_slice(&rgba.to_le_bytes());
+        }
+        out.into_boxed_slice()
+    }
+
+    /// Which buffers hold data (see [`FieldFlags`]). An empty scene reports no fields.
+    pub fn present_fields(&self) -> FieldFlags {
+        let mut flags = FieldFlags::default();
+        if !self.center.is_empty() {
+            flags = flags.with(FieldFlags::CENTER);
+        }
+        if !self.covariance.is_empty() {
+            flags = flags.with(FieldFlags::COVARIANCE);
+        }
+        if !self.rgba.is_empty() {
+            flags = flags.with(FieldFlags::RGBA);
+        }
+        if self.center_f64.as_ref().is_some_and(|c| !c.is_empty()) {
+            flags = flags.with(FieldFlags::CENTER_F64);
+        }
+        if !self.sh.is_empty() {
+            flags = flags.with(FieldFlags::SH);
+        }
+        if !self.scales.is_empty() {
+            flags = flags.with(FieldFlags::SCALE_QUAT);
+        }
+        flags
+    }
+
+    /// Returns a new scene made of the splats at `indices`, in that order.
+    /// Panics if an index is out of range.
+    pub fn select(&self, indices: &[u32]) -> SplatPlyBuffersCore {
+        let mut center: Vec<f32> = Vec::with_capacity(indices.len() * 3);
+        let mut covariance: Vec<f32> = Vec::with_capacity(indices.len() * 6);
+        let mut rgba: Vec<u32> = Vec::with_capacity(indices.len());
+        let mut center_f64: Option<Vec<f64>> = self.center_f64.as_ref().map(|_| Vec::with_capacity(indices.len() * 3));
+        let sh_len = sh_rest_len(self.sh_degree);
+        let mut sh: Vec<f32> = Vec::with_capacity(indices.len() * sh_len);
+        let raw = !self.scales.is_empty();
+        let mut scales: Vec<f32> = Vec::with_capacity(if raw { indices.len() * 3 } else { 0 });
+        let mut quats: Vec<f32> = Vec::with_capacity(if raw { indices.len() * 4 } else { 0 });
+        for &i in indices {
+            let i = i as usize;
+            center.extend_from_slice(&self.center[i * 3..i * 3 + 3]);
+            covariance.extend_from_slice(&self.covariance[i * 6..i * 6 + 6]);
+            rgba.push(self.rgba[i]);
+            if let (Some(dst), Some(src)) = (center_f64.as_mut(), self.center_f64.as_ref()) {
+                dst.extend_from_slice(&src[i * 3..i * 3 + 3]);
+            }
+            sh.extend_from_slice(&self.sh[i * sh_len..(i + 1) * sh_len]);
+            if raw {
+                scales.extend_from_slice(&self.scales[i * 3..i * 3 + 3]);
+                quats.extend_from_slice(&self.quats[i * 4..i * 4 + 4]);
+            }
+        }
+        let mut out = SplatPlyBuffersCore {
+            count: indices.len() as u32,
+            format: self.format,
+            center: center.into_boxed_slice(),
+            covariance: covariance.into_boxed_slice(),
+            rgba: rgba.into_boxed_slice(),
+            bbox_min: [0.0; 3],
+            bbox_max: [0.0; 3],
+            bsphere_center: [0.0; 3],
+            bsphere_radius: 0.0,
+            centroid: [0.0; 3],
+            center_f64: center_f64.map(Vec::into_boxed_slice),
+            sh: sh.into_boxed_slice(),
+            sh_degree: self.sh_degree,
+            scales: scales.into_boxed_slice(),
+            quats: quats.into_boxed_slice(),
+            element_name: self.element_name.clone(),
+            antialiased: self.antialiased,
+            scale_mode: self.scale_mode,
+        };
+        out.recompute_bounds();
+        out
+    }
+
+    /// Recomputes the bbox, bounding sphere and centroid from `center`. An empty scene gets
+    /// `min = +inf`, `max = -inf`.
+    pub fn recompute_bounds(&mut self) {
+        let mut bbox_min = [f32::INFINITY, f32::INFINITY, f32::INFINITY];
+        let mut bbox_max = [f32::NEG_INFINITY, f32::NEG_INFINITY, f32::NEG_INFINITY];
+        for c in self.center.chunks_exact(3) {
+            for k in 0..3 {
+                bbox_min[k] = bbox_min[k].min(c[k]);
+                bbox_max[k] = bbox_max[k].max(c[k]);
+            }
+        }
+        self.bbox_min = bbox_min;
+        self.bbox_max = bbox_max;
+        self.recompute_bounding_sphere();
+        self.recompute_centroid();
+    }
+
+    /// Recomputes `centroid` from `center` and the alpha of `rgba`.
+    pub(crate) fn recompute_centroid(&mut self) {
+        let mut sum = CentroidSum::default();
+        for (c, &rgba) in self.center.chunks_exact(3).zip(&self.rgba[..]) {
+            sum.add([c[0], c[1], c[2]], rgba);
+        }
+        self.centroid = sum.centroid();
+    }
+
+    /// Refits the bounding sphere to the current bbox and `center` (see `bsphere_center`).
+    pub(crate) fn recompute_bounding_sphere(&mut self) {
+        if self.center.is_empty() {
+            self.bsphere_center = [0.0; 3];
+            self.bsphere_radius = 0.0;
+            return;
+        }
+        let mid: [f32; 3] = std::array::from_fn(|k| 0.5 * (self.bbox_min[k] + self.bbox_max[k]));
+        let r2 = self.center.chunks_exact(3).fold(0.0f32, |r2, c| {
+            let d = [c[0] - mid[0], c[1] - mid[1], c[2] - mid[2]];
+            r2.max(d[0] * d[0] + d[1] * d[1] + d[2] * d[2])
+        });
+        self.bsphere_center = mid;
+        self.bsphere_radius = r2.sqrt();
+    }
+
+    /// Drops `scales` and `quats`, for edits that no longer match them.
+    pub fn clear_scale_quat(&mut self) {
+        self.scales = Box::new([]);
+        self.quats = Box::new([]);
+    }
+
+    /// Uniformly scales the scene about the origin: centers (and `center_f64`) by `factor`,
+    /// covariances by `factor²`, `scales` by `|factor|`. The bbox is recomputed.
+    pub fn scale_uniform(&mut self, factor: f32) {
+        self.center.iter_mut().for_each(|v| *v *= factor);
+        self.covariance.iter_mut().for_each(|v| *v *= factor * factor);
+        self.scales.iter_mut().for_each(|v| *v *= factor.abs());
+        if let Some(c) = self.center_f64.as_mut() {
+            c.iter_mut().for_each(|v| *v *= factor as f64);
+        }
+        self.recompute_bounds();
+    }
+
+    /// Applies the column-major affine matrix `m` (bottom row ignored): centers (and
+    /// `center_f64`) become `M·[p, 1]`, covariances `R C Rᵀ` with `R` the upper-left 3x3, so
+    /// scale and shear baked into `m` carry over. `sh` is not rotated, and `scales`/`quats` are
+    /// cleared since shear has no scale + rotation form. The bbox is recomputed.
+    pub fn transform(&mut self, m: &[f32; 16]) {
+        let r = |i: usize, j: usize| m[j * 4 + i];
+        for c in self.center.chunks_exact_mut(3) {
+            let p = [c[0], c[1], c[2]];
+            for i in 0..3 {
+                c[i] = r(i, 0) * p[0] + r(i, 1) * p[1] + r(i, 2) * p[2] + m[12 + i];
+            }
+        }
+        if let Some(c64) = self.center_f64.as_mut() {
+            let r = |i: usize, j: usize| r(i, j) as f64;
+            for c in c64.chunks_exact_mut(3) {
+                let p = [c[0], c[1], c[2]];
+                for i in 0..3 {
+                    c[i] = r(i, 0) * p[0] + r(i, 1) * p[1] + r(i, 2) * p[2] + m[12 + i] as f64;
+                }
+            }
+        }
+        for cov in self.covariance.chunks_exact_mut(6) {
+            let s = [[cov[0], cov[1], cov[2]], [cov[1], cov[3], cov[4]], [cov[2], cov[4], cov[5]]];
+            let rs: [[f32; 3]; 3] =
+                std::array::from_fn(|i| std::array::from_fn(|j| (0..3).map(|k| r(i, k) * s[k][j]).sum()));
+            let rsrt = |i: usize, j: usize| (0..3).map(|k| rs[i][k] * r(j, k)).sum::<f32>();
+            cov.copy_from_slice(&[rsrt(0, 0), rsrt(0, 1), rsrt(0, 2), rsrt(1, 1), rsrt(1, 2), rsrt(2, 2)]);
+        }
+        self.clear_scale_quat();
+        self.recompute_bounds();
+    }
+}
+
+/// Options for [`parse_splat_ply_core_with_options`]. `Default` matches [`parse_splat_ply_core`].
+#[derive(Debug, Clone)]
+pub struct ParseOptionsCore {
+    /// Scales are stored as logarithms (in base `scale_log_base`).
+    pub assume_log_scale: bool,
+    /// Base of the stored scale logarithms when `assume_log_scale` (or the profile, or
+    /// `scale_mode`) says they are logarithmic; [`LogBase::Linear`] reads them as is either way.
+    pub scale_log_base: LogBase,
+    /// Forces the scale encoding, overriding `assume_log_scale` and the profile.
+    /// [`ScaleMode::Auto`] detects it from the data. `None` follows `assume_log_scale`.
+    pub scale_mode: Option<ScaleMode>,
+    /// Opacity is stored as a logit and goes through a sigmoid.
+    pub assume_logit_opacity: bool,
+    /// Forces the opacity encoding. `None` detects 0–255 float alpha (every value in
+    /// `[0, 255]` and some well above any useful logit) and otherwise follows
+    /// `assume_logit_opacity`; detection only runs with [`ExporterProfile::Generic`].
+    /// Neither applies to a byte `alpha`/`a` channel in a file without `opacity`: that byte is
+    /// the alpha as is.
+    pub opacity_mode: Option<OpacityMode>,
+    /// Keep at most this many splats, dropping the least important ones
+    /// (see [`crate::splat_ops_core::splat_importance`]). Survivors keep their file order.
+    pub max_output_splats: Option<usize>,
+    /// Order of the output splats, applied after `max_output_splats`. Every buffer is permuted
+    /// together.
+    pub reorder: ReorderMode,
+    /// Skip splats whose decoded alpha byte (after the opacity encoding and any Mip filter) is
+    /// below this, while decoding, so they never take buffer space. Applied before
+    /// `max_output_splats`; `count` and the bbox cover the kept splats only.
+    pub min_alpha: Option<u8>,
+    /// Handling of non-finite decoded values: a center, a covariance entry (e.g. from an
+    /// infinite scale), `center_f64`, a raw scale or rotation, or an SH coefficient. Colors
+    /// are bytes and never non-finite. `None` keeps the values as read, which can leave the
+    /// bbox infinite or NaN.
+    pub nan_policy: Option<NanPolicy>,
+    /// Reads the quaternion properties (`rot_0..rot_3` or `qx,qy,qz,qw`, in that name order)
+    /// with this layout, for exporters that e.g. write `rot_0..rot_3` as xyzw. `None` picks
+    /// `Wxyz` for `rot_*`, `Xyzw` for `q*`, and `Xyz` when only three exist.
+    pub quat_layout_override: Option<QuatLayout>,
+    /// Element holding the splats. `None` uses `vertex`, or if there is none the first element
+    /// with `x`, `y`, `z` properties (some tools write `splat` or `point`). The element used
+    /// ends up in [`SplatPlyBuffersCore::element_name`].
+    pub element_name: Option<String>,
+    /// When the header says `ascii` but the data looks binary, decode it as
+    /// `binary_little_endian` instead of failing with a diagnostic.
+    pub lenient_format: bool,
+    /// Also fill [`SplatPlyBuffersCore::center_f64`], read from the position properties
+    /// without the f32 downcast (useful for geo-referenced `double` positions).
+    pub center_f64: bool,
+    /// Field names and encodings to expect. Anything but [`ExporterProfile::Generic`]
+    /// overrides `assume_log_scale` / `assume_logit_opacity`.
+    pub exporter_profile: ExporterProfile,
+    /// Binary vertex layout. `None` follows the header's `comment vertex_layout ...` hint
+    /// (see [`PlyHeaderInfo::vertex_layout_hint`]), defaulting to interleaved. Ignored for ascii.
+    pub vertex_layout: Option<VertexLayout>,
+    /// Unit the positions are stored in, converted to meters after decoding.
+    pub unit_hint: UnitHint,
+    /// Apply Mip-Splatting's 3D smoothing filter when the vertex has a `filter_3D` property:
+    /// `Σ' = Σ + filter²·I` (the filter is a Gaussian of standard deviation `filter`
+    /// convolved with the splat), and opacity is scaled by `sqrt(det Σ / det Σ')` so the
+    /// splat's integrated density is unchanged. Skipped for files whose header marks them
+    /// antialiased ([`PlyHeaderInfo::antialiased`]), which already have it baked in.
+    pub apply_mip_filter: bool,
+    /// Also fill [`SplatPlyBuffersCore::scales`] and [`SplatPlyBuffersCore::quats`] with the
+    /// decoded scale and rotation, for re-export or editing without recovering them from the
+    /// covariance. Costs 28 more bytes per splat.
+    pub keep_raw: bool,
+}
+
+impl ParseOptionsCore {
+    /// `(log_scale, logit_opacity)` actually applied: the profile's, or the `assume_*` flags.
+    pub(crate) fn encoding(&self) -> (bool, bool) {
+        self.exporter_profile
+            .encoding()
+            .unwrap_or((self.assume_log_scale, self.assume_logit_opacity))
+    }
+
+    /// How stored scales are turned into linear ones: `scale_log_base` for log scales,
+    /// otherwise [`LogBase::Linear`]. [`ScaleMode::Auto`] is resolved by the decoder; here it
+    /// falls back to `assume_log_scale` like `None`.
+    pub(crate) fn scale_base(&self) -> LogBase {
+        let log = match self.scale_mode {
+            Some(ScaleMode::Log) => true,
+            Some(ScaleMode::Linear) => false,
+            Some(ScaleMode::Auto) | None => self.encoding().0,
+        };
+        if log {
+            self.scale_log_base
+        } else {
+            LogBase::Linear
+        }
+    }
+}
+
+impl Default for ParseOptionsCore {
+    fn default() -> Self {
+        ParseOptionsCore {
+            assume_log_scale: true,
+            scale_log_base: LogBase::Natural,
+            scale_mode: None,
+            assume_logit_opacity: true,
+            opacity_mode: None,
+            max_output_splats: None,
+            reorder: ReorderMode::None,
+            min_alpha: None,
+            nan_policy: None,
+            quat_layout_override: None,
+            element_name: None,
+            lenient_format: false,
+            center_f64: false,
+            exporter_profile: ExporterProfile::Generic,
+            vertex_layout: None,
+            unit_hint: UnitHint::AsIs,
+            apply_mip_filter: false,
+            keep_raw: false,
+        }
+    }
+}
+
+/// Whether scales are stored as logarithms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScaleMode {
+    /// Logarithms in [`ParseOptionsCore::scale_log_base`].
+    Log,
+    /// Linear scales.
+    Linear,
+    /// Log when most scale values among the first few thousand vertices are negative (as
+    /// with `ln(scale)` of splats smaller than one unit; linear scales are never negative),
+    /// otherwise linear. The pick lands in [`SplatPlyBuffersCore::scale_mode`].
+    Auto,
+}
+
+impl ScaleMode {
+    /// `"log"`, `"linear"` or `"auto"` (any case).
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "log" => Some(ScaleMode::Log),
+            "linear" => Some(ScaleMode::Linear),
+            "auto" => Some(ScaleMode::Auto),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScaleMode::Log => "log",
+            ScaleMode::Linear => "linear",
+            ScaleMode::Auto => "auto",
+        }
+    }
+}
+
+/// `true` is [`ScaleMode::Log`], `false` [`ScaleMode::Linear`], as with `assume_log_scale`.
+impl From<bool> for ScaleMode {
+    fn from(log: bool) -> Self {
+        if log {
+            ScaleMode::Log
+        } else {
+            ScaleMode::Linear
+        }
+    }
+}
+
+/// Base of the logarithm scales are stored in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogBase {
+    /// `ln(scale)`; scale = `exp(s)`. What the INRIA trainer writes.
+    #[default]
+    Natural,
+    /// `log2(scale)`; scale = `2^s`.
+    Base2,
+    /// `log10(scale)`; scale = `10^s`.
+    Base10,
+    /// Not a logarithm; scale = `s`.
+    Linear,
+}
+
+impl LogBase {
+    /// Linear scale for the stored value `s`.
+    pub fn apply(self, s: f32) -> f32 {
+        match self {
+            LogBase::Natural => s.exp(),
+            LogBase::Base2 => s.exp2(),
+            LogBase::Base10 => 10f32.powf(s),
+            LogBase::Linear => s,
+        }
+    }
+}
+
+/// How the opacity property is encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpacityMode {
+    /// Logit; alpha = sigmoid(v).
+    Logit,
+    /// Alpha in [0, 1].
+    Linear,
+    /// Alpha in [0, 255] stored as a number; alpha = v / 255.
+    Scaled255,
+}
+
+impl OpacityMode {
+    /// `"logit"`, `"linear"` or `"scaled255"` (any case).
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "logit" => Some(OpacityMode::Logit),
+            "linear" => Some(OpacityMode::Linear),
+            "scaled255" => Some(OpacityMode::Scaled255),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OpacityMode::Logit => "logit",
+            OpacityMode::Linear => "linear",
+            OpacityMode::Scaled255 => "scaled255",
+        }
+    }
+}
+
+/// What a parse does with a vertex holding NaN or infinite values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NanPolicy {
+    /// Fail with a [`PlyError`] naming the first bad vertex.
+    Error,
+    /// Drop the vertex.
+    Skip,
+    /// Replace each non-finite value with 0.
+    Zero,
+}
+
+/// Length unit of the stored positions. Conversions scale centers by [`UnitHint::factor`] and
+/// covariances by its square, so splats keep their shape relative to the scene.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnitHint {
+    /// Keep positions as stored.
+    #[default]
+    AsIs,
+    /// Positions are millimeters; multiply by 0.001.
+    MillimetersToMeters,
+    /// Positions are centimeters; multiply by 0.01.
+    CentimetersToMeters,
+}
+
+impl UnitHint {
+    /// Multiplier applied to positions.
+    pub fn factor(self) -> f32 {
+        match self {
+            UnitHint::AsIs => 1.0,
+            UnitHint::MillimetersToMeters => 0.001,
+            UnitHint::CentimetersToMeters => 0.01,
+        }
+    }
+}
+
+/// Order of the decoded splats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReorderMode {
+    /// File order.
+    #[default]
+    None,
+    /// Ascending Morton code of the centers (see [`crate::splat_ops_core::morton_order`]),
+    /// which keeps spatially close splats together for GPU renderers.
+    Morton,
+}
+
+/// How binary vertex records are arranged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VertexLayout {
+    /// One record per vertex, properties back to back (standard PLY).
+    #[default]
+    Interleaved,
+    /// Struct-of-arrays: all values of the first property, then all of the second, etc.
+    /// Non-standard; files announce it with `comment vertex_layout columnar`.
+    Columnar,
+}
+
+impl VertexLayout {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "interleaved" => Some(VertexLayout::Interleaved),
+            "columnar" => Some(VertexLayout::Columnar),
+            _ => None,
+        }
+    }
+}
+
+/// Tool that wrote the file, selecting which property names are accepted and how scale and
+/// opacity are encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExporterProfile {
+    /// Reference 3DGS training code: `x`, `scale_0..2` (log), `rot_0..3` (wxyz),
+    /// `opacity` (logit), `f_dc_0..2`.
+    Inria,
+    /// Polycam: Inria-style names (scales may be `scale_x..z`), log scale, `opacity`/`alpha`
+    /// stored as a direct alpha in `[0, 1]`, colors in `f_dc_*` or `red/green/blue`.
+    Polycam,
+    /// Luma: Inria-style names or an `qx,qy,qz,qw` quaternion, log scale, logit opacity.
+    Luma,
+    /// Every known alias; encodings come from the `assume_*` options.
+    #[default]
+    Generic,
+}
+
+/// Property name candidates for one [`ExporterProfile`].
+pub(crate) struct ProfileNames {
+    pub(crate) position: [&'static [&'static str]; 3],
+    pub(crate) scale: [&'static [&'static str]; 3],
+    pub(crate) rot_wxyz: Option<[&'static str; 4]>,
+    pub(crate) rot_xyzw: Option<[&'static str; 4]>,
+    pub(crate) opacity: &'static [&'static str],
+    pub(crate) rgb: Option<[&'static [&'static str]; 3]>,
+}
+
+impl ExporterProfile {
+    pub(crate) fn names(self) -> ProfileNames {
+        const INRIA_POSITION: [&[&str]; 3] = [&["x"], &["y"], &["z"]];
+        const INRIA_SCALE: [&[&str]; 3] = [&["scale_0"], &["scale_1"], &["scale_2"]];
+        match self {
+            ExporterProfile::Inria => ProfileNames {
+                position: INRIA_POSITION,
+                scale: INRIA_SCALE,
+                rot_wxyz: Some(ROT_WXYZ_NAMES),
+                rot_xyzw: None,
+                opacity: &["opacity"],
+                rgb: None,
+            },
+            ExporterProfile::Polycam => ProfileNames {
+                position: INRIA_POSITION,
+                scale: [&["scale_0", "scale_x"], &["scale_1", "scale_y"], &["scale_2", "scale_z"]],
+                rot_wxyz: Some(ROT_WXYZ_NAMES),
+                rot_xyzw: None,
+                opacity: &["opacity", "alpha"],
+                rgb: Some([&["red"], &["green"], &["blue"]]),
+            },
+            ExporterProfile::Luma => ProfileNames {
+                position: INRIA_POSITION,
+                scale: INRIA_SCALE,
+                rot_wxyz: Some(ROT_WXYZ_NAMES),
+                rot_xyzw: Some(ROT_XYZW_NAMES),
+                opacity: &["opacity"],
+                rgb: None,
+            },
+            ExporterProfile::Generic => ProfileNames {
+                position: [X_NAMES, Y_NAMES, Z_NAMES],
+                scale: [SCALE_0_NAMES, SCALE_1_NAMES, SCALE_2_NAMES],
+                rot_wxyz: Some(ROT_WXYZ_NAMES),
+                rot_xyzw: Some(ROT_XYZW_NAMES),
+                opacity: OPACITY_NAMES,
+                rgb: Some([RED_NAMES, GREEN_NAMES, BLUE_NAMES]),
+            },
+        }
+    }
+
+    /// `(log_scale, logit_opacity)` this tool writes, or `None` for [`ExporterProfile::Generic`].
+    pub(crate) fn encoding(self) -> Option<(bool, bool)> {
+        match self {
+            ExporterProfile::Inria | ExporterProfile::Luma => Some((true, true)),
+            ExporterProfile::Polycam => Some((true, false)),
+            ExporterProfile::Generic => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -28,23 +794,65 @@ impl PlyFormat {
     }
 }
 
+/// Broad category of a [`PlyError`], for callers that react to failures programmatically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlyErrorKind {
+    /// Malformed or unsupported header.
+    Header,
+    /// A required element (e.g. `vertex`) is missing.
+    MissingElement,
+    /// A required property (position, scale, rotation, opacity, ...) is missing.
+    MissingProperty,
+    /// The data ends before everything the header or format declares.
+    Truncated,
+    /// Data that can't be decoded: unparsable numbers, non-finite values, corrupt caches, ...
+    InvalidData,
+    /// A well-formed file using something this parser doesn't handle, e.g. list properties.
+    Unsupported,
+    /// A bad argument from the caller, e.g. a non-positive voxel size.
+    InvalidArgument,
+    /// Reading from a stream failed.
+    Io,
+}
+
+impl PlyErrorKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PlyErrorKind::Header => "header",
+            PlyErrorKind::MissingElement => "missing_element",
+            PlyErrorKind::MissingProperty => "missing_property",
+            PlyErrorKind::Truncated => "truncated",
+            PlyErrorKind::InvalidData => "invalid_data",
+            PlyErrorKind::Unsupported => "unsupported",
+            PlyErrorKind::InvalidArgument => "invalid_argument",
+            PlyErrorKind::Io => "io",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum PlyError {
-    Msg(&'static str),
-    MsgOwned(String),
+    Msg(PlyErrorKind, &'static str),
+    MsgOwned(PlyErrorKind, String),
 }
 
 impl PlyError {
-    fn msg(s: &'static str) -> Self {
-        PlyError::Msg(s)
+    pub(crate) fn msg(kind: PlyErrorKind, s: &'static str) -> Self {
+        PlyError::Msg(kind, s)
+    }
+
+    pub fn kind(&self) -> PlyErrorKind {
+        match self {
+            PlyError::Msg(kind, _) | PlyError::MsgOwned(kind, _) => *kind,
+        }
     }
 }
 
 impl std::fmt::Display for PlyError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            PlyError::Msg(s) => write!(f, "{s}"),
-            PlyError::MsgOwned(s) => write!(f, "{s}"),
+            PlyError::Msg(_, s) => write!(f, "{s}"),
+            PlyError::MsgOwned(_, s) => write!(f, "{s}"),
         }
     }
 }
@@ -52,7 +860,7 @@ impl std::fmt::Display for PlyError {
 impl std::error::Error for PlyError {}
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum PlyScalarType {
+pub enum PlyScalarType {
     Char,
     UChar,
     Short,
@@ -78,7 +886,7 @@ impl PlyScalarType {
         }
     }
 
-    fn size_bytes(&self) -> usize {
+    pub(crate) fn size_bytes(&self) -> usize {
         match self {
             PlyScalarType::Char => 1,
             PlyScalarType::UChar => 1,
@@ -91,69 +899,253 @@ impl PlyScalarType {
         }
     }
 
-    fn is_probably_byte_color(&self) -> bool {
+    /// `(min, max)` an integer type can hold; `None` for `float`/`double`.
+    pub(crate) fn integer_range(&self) -> Option<(f64, f64)> {
+        match self {
+            PlyScalarType::Char => Some((i8::MIN as f64, i8::MAX as f64)),
+            PlyScalarType::UChar => Some((0.0, u8::MAX as f64)),
+            PlyScalarType::Short => Some((i16::MIN as f64, i16::MAX as f64)),
+            PlyScalarType::UShort => Some((0.0, u16::MAX as f64)),
+            PlyScalarType::Int => Some((i32::MIN as f64, i32::MAX as f64)),
+            PlyScalarType::UInt => Some((0.0, u32::MAX as f64)),
+            PlyScalarType::Float | PlyScalarType::Double => None,
+        }
+    }
+
+    pub(crate) fn is_probably_byte_color(&self) -> bool {
         matches!(self, PlyScalarType::Char | PlyScalarType::UChar)
     }
+
+    /// Type name as written in a PLY header.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PlyScalarType::Char => "char",
+            PlyScalarType::UChar => "uchar",
+            PlyScalarType::Short => "short",
+            PlyScalarType::UShort => "ushort",
+            PlyScalarType::Int => "int",
+            PlyScalarType::UInt => "uint",
+            PlyScalarType::Float => "float",
+            PlyScalarType::Double => "double",
+        }
+    }
 }
 
-#[derive(Clone, Debug)]
-enum PlyProperty {
-    Scalar { name: String, ty: PlyScalarType },
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PlyProperty {
+    Scalar {
+        name: String,
+        ty: PlyScalarType,
+    },
     List {
-        #[allow(dead_code)]
         name: String,
-        #[allow(dead_code)]
         count_ty: PlyScalarType,
-        #[allow(dead_code)]
         item_ty: PlyScalarType,
     },
 }
 
+impl PlyProperty {
+    pub fn name(&self) -> &str {
+        match self {
+            PlyProperty::Scalar { name, .. } | PlyProperty::List { name, .. } => name,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlyElement {
+    pub name: String,
+    pub count: usize,
+    pub properties: Vec<PlyProperty>,
+}
+
+/// A parsed PLY header (see [`parse_ply_header`]).
 #[derive(Clone, Debug)]
-struct PlyElement {
-    name: String,
-    count: usize,
-    properties: Vec<PlyProperty>,
+pub struct PlyHeaderInfo {
+    pub format: PlyFormat,
+    /// Version from the `format` line, e.g. `"1.0"` (empty if the line has none).
+    pub version: String,
+    pub elements: Vec<PlyElement>,
+    /// Byte offset of the first data byte (just past `end_header` and its newline).
+    pub data_offset: usize,
+    /// Text of each `comment` line, without the keyword.
+    pub comments: Vec<String>,
+    pub(crate) newline: Newline,
+}
+
+impl PlyHeaderInfo {
+    /// The vertex layout announced by a `comment vertex_layout <interleaved|columnar>` line.
+    pub fn vertex_layout_hint(&self) -> Option<VertexLayout> {
+        self.comments.iter().find_map(|c| {
+            let mut it = c.split_whitespace();
+            if it.next()? != "vertex_layout" {
+                return None;
+            }
+            VertexLayout::parse(it.next()?)
+        })
+    }
+
+    /// True if a `comment antialiased [true|1]` line marks the splats as trained with a baked-in
+    /// low-pass filter (e.g. Mip-Splatting exports).
+    pub fn antialiased(&self) -> bool {
+        self.comments.iter().any(|c| {
+            let mut it = c.split_whitespace();
+            it.next().is_some_and(|k| k.eq_ignore_ascii_case("antialiased"))
+                && it.next().is_none_or(|v| v.eq_ignore_ascii_case("true") || v == "1")
+        })
+    }
+}
+
+/// Parses only the header: format, elements and their properties. No vertex data is read,
+/// so this is cheap regardless of file size.
+pub fn parse_ply_header(bytes: &[u8]) -> Result<PlyHeaderInfo, PlyError> {
+    parse_header(bytes)
 }
 
+/// Builds PLY header text programmatically.
+///
+/// Element and property names must be non-empty and free of whitespace, and every property
+/// must follow an `element`; [`PlyHeaderBuilder::build`] reports the first violation.
+/// Types are [`PlyScalarType`] values, so only known types can be emitted.
 #[derive(Clone, Debug)]
-struct ParsedHeader {
+pub struct PlyHeaderBuilder {
     format: PlyFormat,
+    comments: Vec<String>,
     elements: Vec<PlyElement>,
-    data_offset: usize,
-    newline: Newline,
+    error: Option<PlyError>,
+}
+
+impl PlyHeaderBuilder {
+    pub fn new(format: PlyFormat) -> Self {
+        PlyHeaderBuilder {
+            format,
+            comments: Vec::new(),
+            elements: Vec::new(),
+            error: None,
+        }
+    }
+
+    pub fn comment(mut self, text: &str) -> Self {
+        if text.contains('\n') || text.contains('\r') {
+            self.fail("PLY header: comments must be single-line");
+        }
+        self.comments.push(text.to_string());
+        self
+    }
+
+    pub fn element(mut self, name: &str, count: usize) -> Self {
+        self.check_name(name, "PLY header: element names must be non-empty without whitespace");
+        self.elements.push(PlyElement {
+            name: name.to_string(),
+            count,
+            properties: Vec::new(),
+        });
+        self
+    }
+
+    pub fn scalar(self, name: &str, ty: PlyScalarType) -> Self {
+        self.property(PlyProperty::Scalar {
+            name: name.to_string(),
+            ty,
+        })
+    }
+
+    pub fn list(self, name: &str, count_ty: PlyScalarType, item_ty: PlyScalarType) -> Self {
+        self.property(PlyProperty::List {
+            name: name.to_string(),
+            count_ty,
+            item_ty,
+        })
+    }
+
+    fn property(mut self, p: PlyProperty) -> Self {
+        self.check_name(p.name(), "PLY header: property names must be non-empty without whitespace");
+        match self.elements.last_mut() {
+            Some(el) => el.properties.push(p),
+            None => self.fail("PLY header: property before element"),
+        }
+        self
+    }
+
+    fn check_name(&mut self, name: &str, msg: &'static str) {
+        if name.is_empty() || name.chars().any(char::is_whitespace) {
+            self.fail(msg);
+        }
+    }
+
+    fn fail(&mut self, msg: &'static str) {
+        if self.error.is_none() {
+            self.error = Some(PlyError::msg(PlyErrorKind::InvalidArgument, msg));
+        }
+    }
+
+    /// Returns the header text (ending with `end_header\n`) and the byte offset where
+    /// element data starts, i.e. the text length.
+    pub fn build(&self) -> Result<(String, usize), PlyError> {
+        if let Some(e) = &self.error {
+            return Err(e.clone());
+        }
+        let mut out = String::new();
+        out.push_str("ply\n");
+        out.push_str(&format!("format {} 1.0\n", self.format.as_str()));
+        for c in self.comments.iter() {
+            out.push_str(&format!("comment {c}\n"));
+        }
+        for el in self.elements.iter() {
+            out.push_str(&format!("element {} {}\n", el.name, el.count));
+            for p in el.properties.iter() {
+                match p {
+                    PlyProperty::Scalar { name, ty } => {
+                        out.push_str(&format!("property {} {name}\n", ty.as_str()));
+                    }
+                    PlyProperty::List {
+                        name,
+                        count_ty,
+                        item_ty,
+                    } => {
+                        out.push_str(&format!(
+                            "property list {} {} {name}\n",
+                            count_ty.as_str(),
+                            item_ty.as_str()
+                        ));
+                    }
+                }
+            }
+        }
+        out.push_str("end_header\n");
+        let len = out.len();
+        Ok((out, len))
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
-enum Newline {
+pub(crate) enum Newline {
     Lf,
     CrLf,
 }
 
 fn find_header_end(bytes: &[u8]) -> Result<(usize, Newline), PlyError> {
-    const PAT: &[u8] = b"end_header";
-    if bytes.len() < PAT.len() {
-        return Err(PlyError::msg("PLY: can't find end_header"));
-    }
-    for i in 0..=(bytes.len() - PAT.len()) {
-        if &bytes[i..i + PAT.len()] != PAT {
-            continue;
-        }
-        let k = i + PAT.len();
-        if k < bytes.len() && bytes[k] == b'\n' {
-            return Ok((k + 1, Newline::Lf));
-        }
-        if k + 1 < bytes.len() && bytes[k] == b'\r' && bytes[k + 1] == b'\n' {
-            return Ok((k + 2, Newline::CrLf));
+    // `end_header` only counts as a whole line (surrounding spaces allowed), so comments
+    // mentioning it and blank lines don't end the header early.
+    let mut start = 0usize;
+    while let Some(len) = bytes[start..].iter().position(|&b| b == b'\n') {
+        let line = &bytes[start..start + len];
+        let (line, newline) = match line.strip_suffix(b"\r") {
+            Some(l) => (l, Newline::CrLf),
+            None => (line, Newline::Lf),
+        };
+        if line.trim_ascii() == b"end_header" {
+            return Ok((start + len + 1, newline));
         }
+        start += len + 1;
     }
-    Err(PlyError::msg("PLY: can't find end_header"))
+    Err(PlyError::msg(PlyErrorKind::Header, "PLY: can't find end_header"))
 }
 
-fn parse_header(bytes: &[u8]) -> Result<ParsedHeader, PlyError> {
+pub(crate) fn parse_header(bytes: &[u8]) -> Result<PlyHeaderInfo, PlyError> {
     let (header_end, newline) = find_header_end(bytes)?;
     let header_text = core::str::from_utf8(&bytes[..header_end])
-        .map_err(|_| PlyError::msg("PLY: header is not valid utf-8"))?;
+        .map_err(|_| PlyError::msg(PlyErrorKind::Header, "PLY: header is not valid utf-8"))?;
 
     let mut lines: Vec<&str> = match newline {
         Newline::Lf => header_text.split('\n').collect(),
@@ -166,10 +1158,12 @@ fn parse_header(bytes: &[u8]) -> Result<ParsedHeader, PlyError> {
         .collect();
 
     if lines.is_empty() || lines[0] != "ply" {
-        return Err(PlyError::msg("PLY: first line must be \"ply\""));
+        return Err(PlyError::msg(PlyErrorKind::Header, "PLY: first line must be \"ply\""));
     }
 
     let mut format: Option<PlyFormat> = None;
+    let mut version = String::new();
+    let mut comments: Vec<String> = Vec::new();
     let mut elements: Vec<PlyElement> = Vec::new();
     let mut current: Option<PlyElement> = None;
 
@@ -181,15 +1175,18 @@ fn parse_header(bytes: &[u8]) -> Result<ParsedHeader, PlyError> {
         let tag = it.next().unwrap_or("");
 
         match tag {
-            "comment" | "obj_info" => {}
+            "comment" => {
+                comments.push(line["comment".len()..].trim().to_string());
+            }
+            "obj_info" => {}
             "format" => {
                 let fmt = it.next().unwrap_or("");
-                let _ver = it.next().unwrap_or("");
+                version = it.next().unwrap_or("").to_string();
                 let f = match fmt {
                     "ascii" => PlyFormat::Ascii,
                     "binary_little_endian" => PlyFormat::BinaryLittleEndian,
                     "binary_big_endian" => PlyFormat::BinaryBigEndian,
-                    _ => return Err(PlyError::msg("PLY: unsupported format")),
+                    _ => return Err(PlyError::msg(PlyErrorKind::Header, "PLY: unsupported format")),
                 };
                 format = Some(f);
             }
@@ -197,13 +1194,13 @@ fn parse_header(bytes: &[u8]) -> Result<ParsedHeader, PlyError> {
                 if let Some(el) = current.take() {
                     elements.push(el);
                 }
-                let name = it.next().ok_or_else(|| PlyError::msg("PLY: bad element"))?;
+                let name = it.next().ok_or_else(|| PlyError::msg(PlyErrorKind::Header, "PLY: bad element"))?;
                 let count_str = it
                     .next()
-                    .ok_or_else(|| PlyError::msg("PLY: bad element count"))?;
+                    .ok_or_else(|| PlyError::msg(PlyErrorKind::Header, "PLY: bad element count"))?;
                 let count: usize = count_str
                     .parse()
-                    .map_err(|_| PlyError::msg("PLY: bad element count"))?;
+                    .map_err(|_| PlyError::msg(PlyErrorKind::Header, "PLY: bad element count"))?;
                 current = Some(PlyElement {
                     name: name.to_string(),
                     count,
@@ -213,22 +1210,22 @@ fn parse_header(bytes: &[u8]) -> Result<ParsedHeader, PlyError> {
             "property" => {
                 let cur = current
                     .as_mut()
-                    .ok_or_else(|| PlyError::msg("PLY: property before element"))?;
-                let t1 = it.next().ok_or_else(|| PlyError::msg("PLY: bad property"))?;
+                    .ok_or_else(|| PlyError::msg(PlyErrorKind::Header, "PLY: property before element"))?;
+                let t1 = it.next().ok_or_else(|| PlyError::msg(PlyErrorKind::Header, "PLY: bad property"))?;
                 if t1 == "list" {
                     let count_t = it
                         .next()
-                        .ok_or_else(|| PlyError::msg("PLY: bad list property"))?;
+                        .ok_or_else(|| PlyError::msg(PlyErrorKind::Header, "PLY: bad list property"))?;
                     let item_t = it
                         .next()
-                        .ok_or_else(|| PlyError::msg("PLY: bad list property"))?;
+                        .ok_or_else(|| PlyError::msg(PlyErrorKind::Header, "PLY: bad list property"))?;
                     let name = it
                         .next()
-                        .ok_or_else(|| PlyError::msg("PLY: bad list property"))?;
+                        .ok_or_else(|| PlyError::msg(PlyErrorKind::Header, "PLY: bad list property"))?;
                     let count_ty =
-                        PlyScalarType::parse(count_t).ok_or_else(|| PlyError::msg("PLY: bad list type"))?;
+                        PlyScalarType::parse(count_t).ok_or(PlyError::msg(PlyErrorKind::Header, "PLY: bad list type"))?;
                     let item_ty =
-                        PlyScalarType::parse(item_t).ok_or_else(|| PlyError::msg("PLY: bad list type"))?;
+                        PlyScalarType::parse(item_t).ok_or(PlyError::msg(PlyErrorKind::Header, "PLY: bad list type"))?;
                     cur.properties.push(PlyProperty::List {
                         name: name.to_string(),
                         count_ty,
@@ -236,33 +1233,38 @@ fn parse_header(bytes: &[u8]) -> Result<ParsedHeader, PlyError> {
                     });
                 } else {
                     let ty = PlyScalarType::parse(t1)
-                        .ok_or_else(|| PlyError::msg("PLY: bad scalar type"))?;
+                        .ok_or_else(|| PlyError::msg(PlyErrorKind::Header, "PLY: bad scalar type"))?;
                     let name = it
                         .next()
-                        .ok_or_else(|| PlyError::msg("PLY: bad scalar property"))?;
+                        .ok_or_else(|| PlyError::msg(PlyErrorKind::Header, "PLY: bad scalar property"))?;
                     cur.properties.push(PlyProperty::Scalar {
                         name: name.to_string(),
                         ty,
                     });
                 }
             }
-            _ => return Err(PlyError::msg("PLY: unknown header directive")),
+            _ => return Err(PlyError::msg(PlyErrorKind::Header, "PLY: unknown header directive")),
         }
     }
 
     if let Some(el) = current.take() {
         elements.push(el);
     }
-    let format = format.ok_or_else(|| PlyError::msg("PLY: missing format"))?;
+    let format = format.ok_or_else(|| PlyError::msg(PlyErrorKind::Header, "PLY: missing format"))?;
 
-    Ok(ParsedHeader {
+    Ok(PlyHeaderInfo {
         format,
+        version,
         elements,
         data_offset: header_end,
+        comments,
         newline,
     })
 }
 
+/// Degree-0 spherical harmonics basis constant: `color = 0.5 + SH_C0 * f_dc`.
+pub(crate) const SH_C0: f32 = 0.282_094_8;
+
 fn sigmoid(x: f32) -> f32 {
     if x >= 0.0 {
         let z = (-x).exp();
@@ -283,8 +1285,8 @@ fn clamp255(x: f32) -> u32 {
     }
 }
 
-fn rgba_to_u32(r: u32, g: u32, b: u32, a: u32) -> u32 {
-    ((r & 255) | ((g & 255) << 8) | ((b & 255) << 16) | ((a & 255) << 24)) as u32
+pub(crate) fn rgba_to_u32(r: u32, g: u32, b: u32, a: u32) -> u32 {
+    (r & 255) | ((g & 255) << 8) | ((b & 255) << 16) | ((a & 255) << 24)
 }
 
 fn normalize_quat(x: f32, y: f32, z: f32, w: f32) -> (f32, f32, f32, f32) {
@@ -319,7 +1321,7 @@ fn quat_to_mat3_cols(x: f32, y: f32, z: f32, w: f32) -> ([f32; 3], [f32; 3], [f3
     ([r00, r10, r20], [r01, r11, r21], [r02, r12, r22])
 }
 
-fn covariance_from_quat_scale(qx: f32, qy: f32, qz: f32, qw: f32, sx: f32, sy: f32, sz: f32) -> [f32; 6] {
+pub(crate) fn covariance_from_quat_scale(qx: f32, qy: f32, qz: f32, qw: f32, sx: f32, sy: f32, sz: f32) -> [f32; 6] {
     let (x, y, z, w) = normalize_quat(qx, qy, qz, qw);
     let (c0, c1, c2) = quat_to_mat3_cols(x, y, z, w);
 
@@ -339,22 +1341,129 @@ fn covariance_from_quat_scale(qx: f32, qy: f32, qz: f32, qw: f32, sx: f32, sy: f
     [m11, m12, m13, m22, m23, m33]
 }
 
+/// Property name candidates the parser accepts for each splat field (matched lowercased).
+pub(crate) const X_NAMES: &[&str] = &["x", "pos_x", "position_x"];
+pub(crate) const Y_NAMES: &[&str] = &["y", "pos_y", "position_y"];
+pub(crate) const Z_NAMES: &[&str] = &["z", "pos_z", "position_z"];
+pub(crate) const SCALE_0_NAMES: &[&str] = &["scale_0", "sx", "scale_x", "scalex"];
+pub(crate) const SCALE_1_NAMES: &[&str] = &["scale_1", "sy", "scale_y", "scaley"];
+pub(crate) const SCALE_2_NAMES: &[&str] = &["scale_2", "sz", "scale_z", "scalez"];
+pub(crate) const ROT_WXYZ_NAMES: [&str; 4] = ["rot_0", "rot_1", "rot_2", "rot_3"];
+pub(crate) const ROT_XYZW_NAMES: [&str; 4] = ["qx", "qy", "qz", "qw"];
+pub(crate) const OPACITY_NAMES: &[&str] = &["opacity", "alpha", "opac"];
+/// Fourth color channel of RGBA point clouds; a byte one is read as the packed alpha.
+pub(crate) const ALPHA_NAMES: &[&str] = &["alpha", "a"];
+/// Mip-Splatting's per-splat 3D low-pass filter size.
+pub(crate) const MIP_FILTER_NAMES: &[&str] = &["filter_3D"];
+pub(crate) const RED_NAMES: &[&str] = &["red", "r"];
+pub(crate) const GREEN_NAMES: &[&str] = &["green", "g"];
+pub(crate) const BLUE_NAMES: &[&str] = &["blue", "b"];
+/// SH DC coefficients; some tools write `sh_0..2` instead of `f_dc_0..2`.
+pub(crate) const DC_NAMES: [&[&str]; 3] = [&["f_dc_0", "sh_0"], &["f_dc_1", "sh_1"], &["f_dc_2", "sh_2"]];
+pub(crate) const L_MATRIX_NAMES: [&str; 9] =
+    ["l_00", "l_01", "l_02", "l_10", "l_11", "l_12", "l_20", "l_21", "l_22"];
+/// Higher-order SH coefficients are named `f_rest_0`, `f_rest_1`, ...
+pub(crate) const SH_REST_PREFIX: &str = "f_rest_";
+
+/// True if `name` (any case) is one of the properties the splat decoder reads.
+pub(crate) fn is_splat_property(name: &str) -> bool {
+    let name = name.to_lowercase();
+    let lists: [&[&str]; 13] = [
+        X_NAMES,
+        Y_NAMES,
+        Z_NAMES,
+        SCALE_0_NAMES,
+        SCALE_1_NAMES,
+        SCALE_2_NAMES,
+        &ROT_WXYZ_NAMES,
+        &ROT_XYZW_NAMES,
+        OPACITY_NAMES,
+        ALPHA_NAMES,
+        RED_NAMES,
+        GREEN_NAMES,
+        BLUE_NAMES,
+    ];
+    lists.iter().any(|l| l.contains(&name.as_str()))
+        || DC_NAMES.iter().any(|l| l.contains(&name.as_str()))
+        || L_MATRIX_NAMES.contains(&name.as_str())
+        || MIP_FILTER_NAMES.iter().any(|n| n.eq_ignore_ascii_case(&name))
+        || name.starts_with(SH_REST_PREFIX)
+}
+
+/// `L Lᵀ` for a row-major 3x3 `l`, packed as `[m11, m12, m13, m22, m23, m33]`.
+fn covariance_from_matrix(l: &[f32; 9]) -> [f32; 6] {
+    let row = |r: usize| [l[r * 3], l[r * 3 + 1], l[r * 3 + 2]];
+    let dot = |a: [f32; 3], b: [f32; 3]| a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+    let (r0, r1, r2) = (row(0), row(1), row(2));
+    [dot(r0, r0), dot(r0, r1), dot(r0, r2), dot(r1, r1), dot(r1, r2), dot(r2, r2)]
+}
+
+/// Component order of the stored quaternion properties (see
+/// [`ParseOptionsCore::quat_layout_override`]).
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum QuatLayout {
+pub enum QuatLayout {
     /// Quaternion stored as (w, x, y, z). Common for PLY fields `rot_0..rot_3`.
     Wxyz,
     /// Quaternion stored as (x, y, z, w). Some PLY variants use `qx,qy,qz,qw`.
     Xyzw,
+    /// Only (x, y, z) of a unit quaternion (`rot_0..rot_2` or `qx,qy,qz`); `w` is rebuilt as
+    /// `+sqrt(1 - x² - y² - z²)`. `q` and `-q` are the same rotation, so this is lossless as
+    /// long as the writer flipped quaternions to `w >= 0` before dropping `w`.
+    Xyz,
 }
 
-fn read_scalar(bytes: &[u8], offset: usize, ty: PlyScalarType, little: bool) -> Result<f64, PlyError> {
-    let need = ty.size_bytes();
-    if offset + need > bytes.len() {
-        return Err(PlyError::msg("PLY: out of bounds while reading binary data"));
+impl QuatLayout {
+    /// `"wxyz"`, `"xyzw"` or `"xyz"` (any case).
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "wxyz" => Some(QuatLayout::Wxyz),
+            "xyzw" => Some(QuatLayout::Xyzw),
+            "xyz" => Some(QuatLayout::Xyz),
+            _ => None,
+        }
     }
 
-    let b = &bytes[offset..offset + need];
-    let v = match ty {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QuatLayout::Wxyz => "wxyz",
+            QuatLayout::Xyzw => "xyzw",
+            QuatLayout::Xyz => "xyz",
+        }
+    }
+
+    /// Number of stored components.
+    fn components(self) -> usize {
+        match self {
+            QuatLayout::Wxyz | QuatLayout::Xyzw => 4,
+            QuatLayout::Xyz => 3,
+        }
+    }
+}
+
+/// Checks that `rows` records of `stride` bytes starting at `base` fit in `len` bytes before any
+/// decoding starts, reporting expected vs actual sizes for truncated files.
+fn check_binary_len(base: usize, rows: usize, stride: usize, len: usize) -> Result<(), PlyError> {
+    let required = base as u128 + rows as u128 * stride as u128;
+    if required > len as u128 {
+        return Err(PlyError::MsgOwned(
+            PlyErrorKind::Truncated,
+            format!(
+                "PLY: {rows} vertices of {stride} bytes need {required} bytes of data, but the file has {len} \
+                 (truncated download?)"
+            ),
+        ));
+    }
+    Ok(())
+}
+
+fn read_scalar(bytes: &[u8], offset: usize, ty: PlyScalarType, little: bool) -> Result<f64, PlyError> {
+    let need = ty.size_bytes();
+    if offset + need > bytes.len() {
+        return Err(PlyError::msg(PlyErrorKind::Truncated, "PLY: out of bounds while reading binary data"));
+    }
+
+    let b = &bytes[offset..offset + need];
+    let v = match ty {
         PlyScalarType::Char => i8::from_ne_bytes([b[0]]) as f64,
         PlyScalarType::UChar => u8::from_ne_bytes([b[0]]) as f64,
         PlyScalarType::Short => {
@@ -384,8 +1493,7 @@ fn read_scalar(bytes: &[u8], offset: usize, ty: PlyScalarType, little: bool) ->
         }
         PlyScalarType::Double => {
             let arr = [b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]];
-            let n = if little { f64::from_le_bytes(arr) } else { f64::from_be_bytes(arr) };
-            n
+            if little { f64::from_le_bytes(arr) } else { f64::from_be_bytes(arr) }
         }
     };
     Ok(v)
@@ -400,354 +1508,2350 @@ fn pick_name(map: &HashMap<String, (usize, PlyScalarType)>, names: &[&str]) -> O
     None
 }
 
+/// The fields named by `names`, in order, if every one of them exists.
+fn pick_all<const N: usize>(map: &HashMap<String, (usize, PlyScalarType)>, names: [&str; N]) -> Option<[Field; N]> {
+    let mut fields = [(0, PlyScalarType::Float); N];
+    for (field, name) in fields.iter_mut().zip(names) {
+        *field = pick_name(map, &[name])?;
+    }
+    Some(fields)
+}
+
+/// Maps each scalar property of `el` (lowercased name) to its column index in an ASCII data line.
+fn scalar_columns(el: &PlyElement) -> HashMap<String, usize> {
+    let mut name_to_col: HashMap<String, usize> = HashMap::new();
+    let mut scalar_i = 0usize;
+    for p in el.properties.iter() {
+        if let PlyProperty::Scalar { name, .. } = p {
+            name_to_col.insert(name.to_lowercase(), scalar_i);
+            scalar_i += 1;
+        }
+    }
+    name_to_col
+}
+
+/// The element holding the splats: `vertex` if there is one, else the first element with
+/// position properties (as some tools name it `splat` or `point`).
+pub(crate) fn find_vertex_element(header: &PlyHeaderInfo) -> Result<&PlyElement, PlyError> {
+    let has_position = |e: &&PlyElement| {
+        let pmap = property_map(e);
+        [X_NAMES, Y_NAMES, Z_NAMES].iter().all(|names| pick_name(&pmap, names).is_some())
+    };
+    let elements = &header.elements;
+    elements
+        .iter()
+        .find(|e| e.name.eq_ignore_ascii_case("vertex"))
+        .or_else(|| elements.iter().find(has_position))
+        .ok_or_else(|| {
+            PlyError::msg(
+                PlyErrorKind::MissingElement,
+                "PLY: element \"vertex\" not found, and no element has x, y, z properties",
+            )
+        })
+}
+
+/// The element named [`ParseOptionsCore::element_name`] (any case) if set, else
+/// [`find_vertex_element`].
+fn find_splat_element<'a>(header: &'a PlyHeaderInfo, opts: &ParseOptionsCore) -> Result<&'a PlyElement, PlyError> {
+    match &opts.element_name {
+        Some(name) => header
+            .elements
+            .iter()
+            .find(|e| e.name.eq_ignore_ascii_case(name))
+            .ok_or_else(|| {
+                PlyError::MsgOwned(PlyErrorKind::MissingElement, format!("PLY: element \"{name}\" not found"))
+            }),
+        None => find_vertex_element(header),
+    }
+}
+
+/// Lists the vertex scalar properties the splat decoder doesn't read (anything besides
+/// position, scale, rotation, opacity, color and SH), i.e. the data a parse drops.
+pub fn ignored_properties(bytes: &[u8]) -> Result<Vec<(String, PlyScalarType)>, PlyError> {
+    ignored_properties_with_padding(bytes, &[])
+}
+
+/// [`ignored_properties`], also leaving out the properties named in `padding` (any case).
+/// Padding properties carry no data, e.g. a `uchar pad` that aligns records; they are
+/// still counted in the record stride, so decoding is unaffected either way.
+pub fn ignored_properties_with_padding(
+    bytes: &[u8],
+    padding: &[&str],
+) -> Result<Vec<(String, PlyScalarType)>, PlyError> {
+    let header = parse_header(bytes)?;
+    let el = find_vertex_element(&header)?;
+    let is_padding = |name: &str| padding.iter().any(|p| p.eq_ignore_ascii_case(name));
+    Ok(el
+        .properties
+        .iter()
+        .filter_map(|p| match p {
+            PlyProperty::Scalar { name, ty } if !is_splat_property(name) && !is_padding(name) => {
+                Some((name.clone(), *ty))
+            }
+            _ => None,
+        })
+        .collect())
+}
+
+/// Returns the vertex property name (lowercased) → ASCII column index mapping the parser uses.
+/// Useful to check that columns line up with expectations before running a full parse.
+pub fn ascii_column_map(bytes: &[u8]) -> Result<HashMap<String, usize>, PlyError> {
+    let header = parse_header(bytes)?;
+    let el = find_vertex_element(&header)?;
+    Ok(scalar_columns(el))
+}
+
 pub fn parse_splat_ply_core(bytes: &[u8]) -> Result<SplatPlyBuffersCore, PlyError> {
     parse_splat_ply_core_with_opts(bytes, true, true)
 }
 
+/// Leading bytes of a gzip stream.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Most bytes [`parse_splat_ply_core_gz`] decompresses (2 GiB, about 8.6M splats with SH
+/// degree 3), so a small gzip bomb can't exhaust memory.
+const MAX_GZIP_OUTPUT: u64 = 2 << 30;
+
+/// Like [`parse_splat_ply_core`], but accepts a gzip-compressed PLY (`.ply.gz`) as well as a
+/// plain one: input starting with the gzip magic bytes is decompressed first (concatenated
+/// gzip members included), anything else is parsed as is. Fails if the decompressed PLY is
+/// larger than 2 GiB.
+pub fn parse_splat_ply_core_gz(bytes: &[u8]) -> Result<SplatPlyBuffersCore, PlyError> {
+    if !bytes.starts_with(&GZIP_MAGIC) {
+        return parse_splat_ply_core(bytes);
+    }
+    let mut plain = Vec::new();
+    let decoder = flate2::read::MultiGzDecoder::new(bytes);
+    std::io::Read::read_to_end(&mut std::io::Read::take(decoder, MAX_GZIP_OUTPUT + 1), &mut plain)
+        .map_err(|e| PlyError::MsgOwned(PlyErrorKind::InvalidData, format!("PLY: gzip decompression failed: {e}")))?;
+    if plain.len() as u64 > MAX_GZIP_OUTPUT {
+        return Err(PlyError::MsgOwned(
+            PlyErrorKind::Unsupported,
+            format!("PLY: gzip data decompresses to more than {MAX_GZIP_OUTPUT} bytes"),
+        ));
+    }
+    parse_splat_ply_core(&plain)
+}
+
+/// Parses with the given scale encoding (a [`ScaleMode`], or a bool as for `assume_log_scale`)
+/// and opacity encoding.
 pub fn parse_splat_ply_core_with_opts(
     bytes: &[u8],
-    assume_log_scale: bool,
+    scale_mode: impl Into<ScaleMode>,
     assume_logit_opacity: bool,
 ) -> Result<SplatPlyBuffersCore, PlyError> {
-    let header = parse_header(bytes)?;
-    let vertex_name = "vertex";
-    let el = header
-        .elements
-        .iter()
-        .find(|e| e.name.to_lowercase() == vertex_name)
-        .ok_or_else(|| PlyError::msg("PLY: element \"vertex\" not found"))?;
+    let opts = ParseOptionsCore {
+        scale_mode: Some(scale_mode.into()),
+        assume_logit_opacity,
+        ..ParseOptionsCore::default()
+    };
+    parse_splat_ply_core_with_options(bytes, &opts)
+}
 
-    if el.properties.iter().any(|p| matches!(p, PlyProperty::List { .. })) {
-        return Err(PlyError::msg(
-            "PLY: vertex has list properties — not supported by this minimal splat parser",
+pub fn parse_splat_ply_core_with_options(
+    bytes: &[u8],
+    opts: &ParseOptionsCore,
+) -> Result<SplatPlyBuffersCore, PlyError> {
+    parse_splat_ply_core_traced(bytes, opts, None)
+}
+
+/// Like [`parse_splat_ply_core_with_options`], calling `on_event` with a short message at each
+/// milestone: header parsed, format detected, fields resolved, decode started and finished.
+/// Messages are only formatted when a callback is given.
+pub fn parse_splat_ply_core_traced(
+    bytes: &[u8],
+    opts: &ParseOptionsCore,
+    on_event: Option<&mut dyn FnMut(&str)>,
+) -> Result<SplatPlyBuffersCore, PlyError> {
+    parse_transformed(bytes, opts, &IDENTITY_MAT4, &mut Trace::new(on_event))
+}
+
+/// Like [`parse_splat_ply_core_with_options`], calling `on_progress` with the fraction of
+/// vertices decoded so far: 0.0 at the start, then about every 1% of the vertices, and 1.0
+/// when decoding ends. The native `parallel` decode only reports the start and the end.
+pub fn parse_splat_ply_core_with_progress(
+    bytes: &[u8],
+    opts: &ParseOptionsCore,
+    on_progress: &mut dyn FnMut(f32),
+) -> Result<SplatPlyBuffersCore, PlyError> {
+    let mut trace = Trace::new(None);
+    trace.on_progress = Some(on_progress);
+    parse_transformed(bytes, opts, &IDENTITY_MAT4, &mut trace)
+}
+
+/// Parses with `opts`, then collapses the splats into a grid of `voxel_size` cubes for preview
+/// rendering: one opacity-weighted merged splat per occupied voxel (see [`voxel_downsample`]),
+/// so `count` is the number of occupied voxels and the bbox covers the merged centers. Fails
+/// unless `voxel_size` is positive and finite.
+pub fn parse_splat_ply_core_downsampled(
+    bytes: &[u8],
+    opts: &ParseOptionsCore,
+    voxel_size: f32,
+) -> Result<SplatPlyBuffersCore, PlyError> {
+    let out = parse_transformed(bytes, opts, &IDENTITY_MAT4, &mut Trace::new(None))?;
+    voxel_downsample(&out, voxel_size)
+}
+
+/// Parses with `opts`, keeping each decoded vertex with probability `fraction` for quick
+/// previews. The draws come from a [`SplitMix64`] seeded with `seed`, one per vertex in file
+/// order before any reordering, so the same file, `fraction` and `seed` keep the same splats
+/// on every run and platform. `count` and the bbox cover the survivors. Fails unless
+/// `fraction` is in `[0, 1]`.
+pub fn parse_splat_ply_core_subsampled(
+    bytes: &[u8],
+    opts: &ParseOptionsCore,
+    fraction: f32,
+    seed: u64,
+) -> Result<SplatPlyBuffersCore, PlyError> {
+    if !(0.0..=1.0).contains(&fraction) {
+        return Err(PlyError::MsgOwned(
+            PlyErrorKind::InvalidArgument,
+            format!("PLY: subsample fraction must be in [0, 1], got {fraction}"),
+        ));
+    }
+    let mut trace = Trace::new(None);
+    let out = decode_splats(bytes, opts, &mut trace)?;
+    let mut rng = SplitMix64::new(seed);
+    let keep: Vec<u32> = (0..out.count).filter(|_| rng.next_f32() < fraction).collect();
+    Ok(postprocess(out.select(&keep), opts, &mut trace))
+}
+
+/// Column-major 4x4 identity: the model transform of the default entrypoints.
+const IDENTITY_MAT4: [f32; 16] = [
+    1.0, 0.0, 0.0, 0.0, //
+    0.0, 1.0, 0.0, 0.0, //
+    0.0, 0.0, 1.0, 0.0, //
+    0.0, 0.0, 0.0, 1.0,
+];
+
+/// Like [`parse_splat_ply_core_with_options`], then places the scene with the column-major
+/// model matrix `transform` (see [`SplatPlyBuffersCore::transform`]). The bbox is in
+/// transformed space. Runs after `unit_hint`, so the translation is in the converted units.
+pub fn parse_splat_ply_core_with_transform(
+    bytes: &[u8],
+    opts: &ParseOptionsCore,
+    transform: &[f32; 16],
+) -> Result<SplatPlyBuffersCore, PlyError> {
+    parse_transformed(bytes, opts, transform, &mut Trace::new(None))
+}
+
+fn parse_transformed(
+    bytes: &[u8],
+    opts: &ParseOptionsCore,
+    transform: &[f32; 16],
+    trace: &mut Trace,
+) -> Result<SplatPlyBuffersCore, PlyError> {
+    let out = decode_splats(bytes, opts, trace)?;
+    let mut out = postprocess(out, opts, trace);
+    if *transform != IDENTITY_MAT4 {
+        out.transform(transform);
+        trace.emit(|| "transform: applied model matrix".to_string());
+    }
+    Ok(out)
+}
+
+/// Options applied to the decoded scene: `unit_hint`, then `max_output_splats`.
+fn postprocess(mut out: SplatPlyBuffersCore, opts: &ParseOptionsCore, trace: &mut Trace) -> SplatPlyBuffersCore {
+    if opts.unit_hint != UnitHint::AsIs {
+        out.scale_uniform(opts.unit_hint.factor());
+        trace.emit(|| format!("unit_hint: scaled positions by {}", opts.unit_hint.factor()));
+    }
+    if let Some(max) = opts.max_output_splats {
+        let before = out.count;
+        keep_most_important(&mut out, max);
+        trace.emit(|| format!("max_output_splats: kept {} of {before}", out.count));
+    }
+    if opts.reorder == ReorderMode::Morton {
+        out = out.select(&morton_order(&out));
+        trace.emit(|| "reorder: sorted by Morton code".to_string());
+    }
+    out
+}
+
+/// A splat standard deviation larger than this times the bbox diagonal is implausible.
+const SUSPICIOUS_SCALE_RATIO: f32 = 1.0;
+
+/// A bbox diagonal above this many units suggests positions in millimeters or centimeters.
+const SUSPICIOUS_SCENE_EXTENT: f32 = 1000.0;
+
+/// Human-readable messages from [`parse_splat_ply_core_verbose`]. Neither list ever fails the
+/// parse.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParseDiagnostics {
+    /// Encoding decisions the decoder made, e.g. how scales and opacity were read and whether
+    /// colors were taken as float 0–1 or 0–255 bytes and why. Reported for every file.
+    pub decisions: Vec<String>,
+    /// Results that look wrong, e.g. scales far larger than the scene when `assume_log_scale`
+    /// was applied to linear scales, or a scene so large its positions are likely not meters.
+    /// Empty for a clean file.
+    pub warnings: Vec<String>,
+}
+
+/// Like [`parse_splat_ply_core_with_options`], also returning [`ParseDiagnostics`] for
+/// diagnosing wrong-looking output.
+pub fn parse_splat_ply_core_verbose(
+    bytes: &[u8],
+    opts: &ParseOptionsCore,
+) -> Result<(SplatPlyBuffersCore, ParseDiagnostics), PlyError> {
+    let mut trace = Trace {
+        on_event: None,
+        on_progress: None,
+        decisions: Some(Vec::new()),
+    };
+    let out = parse_transformed(bytes, opts, &IDENTITY_MAT4, &mut trace)?;
+    let decisions = trace.decisions.unwrap_or_default();
+    let mut warnings = Vec::new();
+
+    let diagonal = (0..3)
+        .map(|k| (out.bbox_max[k] - out.bbox_min[k]).powi(2))
+        .sum::<f32>()
+        .sqrt();
+    // Auto already picked the encoding from the data, and the pick is among the decisions.
+    if opts.scale_mode != Some(ScaleMode::Auto) && opts.scale_base() != LogBase::Linear && out.count > 1 {
+        let stats = scale_stats(&out);
+        let max_scale = stats.max.iter().fold(0.0f32, |m, &v| m.max(v));
+        if max_scale > SUSPICIOUS_SCALE_RATIO * diagonal {
+            warnings.push(format!(
+                "largest splat scale {max_scale} exceeds the scene bbox diagonal {diagonal}; \
+                 the scales may be linear rather than log (try assume_log_scale = false)"
+            ));
+        }
+    }
+    if opts.unit_hint == UnitHint::AsIs && out.count > 1 && diagonal > SUSPICIOUS_SCENE_EXTENT {
+        warnings.push(format!(
+            "scene bbox diagonal is {diagonal} units; positions may be millimeters or centimeters \
+             (try unit_hint = MillimetersToMeters or CentimetersToMeters)"
         ));
     }
+    Ok((out, ParseDiagnostics { decisions, warnings }))
+}
+
+/// Like [`parse_splat_ply_core_with_options`], also returning a plain-text "what did I just
+/// parse" dump for bug reports: the detected format and count, every parse milestone (which
+/// property feeds each field, the quaternion layout, the encodings), then the first and last
+/// splat's center, covariance and rgba. Nothing is formatted on the other entrypoints.
+pub fn parse_splat_ply_core_debug(
+    bytes: &[u8],
+    opts: &ParseOptionsCore,
+) -> Result<(SplatPlyBuffersCore, String), PlyError> {
+    let mut events: Vec<String> = Vec::new();
+    let mut collect = |msg: &str| events.push(msg.to_string());
+    let out = parse_transformed(bytes, opts, &IDENTITY_MAT4, &mut Trace::new(Some(&mut collect)))?;
+
+    let mut dump = format!("parsed {} splats ({})\n", out.count, out.format.as_str());
+    for event in &events {
+        dump.push_str(event);
+        dump.push('\n');
+    }
+    let last = (out.count as usize).saturating_sub(1);
+    for (label, index) in [("first", 0), ("last", last)] {
+        if let Some((center, cov, rgba)) = out.splat(index) {
+            let [r, g, b, a] = rgba.to_le_bytes();
+            dump.push_str(&format!(
+                "{label} splat #{index}: center {center:?}, covariance {cov:?}, rgba ({r}, {g}, {b}, {a})\n"
+            ));
+        }
+    }
+    Ok((out, dump))
+}
+
+/// Progress reports per decode, not counting the final 1.0.
+const PROGRESS_STEPS: usize = 100;
+
+/// Sink for parse milestones, optionally also collecting the decoder's encoding decisions
+/// (see [`parse_splat_ply_core_verbose`]).
+struct Trace<'a> {
+    on_event: Option<&'a mut dyn FnMut(&str)>,
+    /// Fraction of vertices decoded (see [`parse_splat_ply_core_with_progress`]).
+    on_progress: Option<&'a mut dyn FnMut(f32)>,
+    decisions: Option<Vec<String>>,
+}
+
+impl<'a> Trace<'a> {
+    fn new(on_event: Option<&'a mut dyn FnMut(&str)>) -> Self {
+        Trace {
+            on_event,
+            on_progress: None,
+            decisions: None,
+        }
+    }
+
+    /// Reports `done` of `total` vertices decoded when that is a multiple of `total /`
+    /// [`PROGRESS_STEPS`] or the end, so the callback runs about that many times per decode.
+    fn progress(&mut self, done: usize, total: usize) {
+        if let Some(f) = self.on_progress.as_mut()
+            && (done.is_multiple_of((total / PROGRESS_STEPS).max(1)) || done == total)
+        {
+            f(if total == 0 { 1.0 } else { done as f32 / total as f32 });
+        }
+    }
+
+    fn emit(&mut self, msg: impl FnOnce() -> String) {
+        if let Some(f) = self.on_event.as_mut() {
+            f(&msg());
+        }
+    }
+
+    /// Emits `msg` like [`Trace::emit`] and records it as a decision if those are collected.
+    fn decide(&mut self, msg: impl FnOnce() -> String) {
+        if self.on_event.is_none() && self.decisions.is_none() {
+            return;
+        }
+        let msg = msg();
+        if let Some(f) = self.on_event.as_mut() {
+            f(&msg);
+        }
+        if let Some(d) = self.decisions.as_mut() {
+            d.push(msg);
+        }
+    }
+}
+
+/// Byte size of one binary record of `el`, or `None` if it has list properties.
+pub(crate) fn binary_stride(el: &PlyElement) -> Option<usize> {
+    el.properties
+        .iter()
+        .map(|p| match p {
+            PlyProperty::Scalar { ty, .. } => Some(ty.size_bytes()),
+            PlyProperty::List { .. } => None,
+        })
+        .sum()
+}
+
+/// True when an `ascii` file's first non-blank data line isn't made of numbers but the data is
+/// large enough to hold `el` as packed binary records — the usual sign of a mislabeled `format`
+/// line.
+fn looks_like_mislabeled_binary(bytes: &[u8], header: &PlyHeaderInfo, el: &PlyElement) -> bool {
+    let data = &bytes[header.data_offset..];
+    // Blank lines (e.g. a stray `\r\n` after an LF header) say nothing either way.
+    let first_line = data.split(|&b| b == b'\n').find(|l| !l.trim_ascii().is_empty()).unwrap_or(&[]);
+    let numeric = match core::str::from_utf8(first_line) {
+        Ok(line) => {
+            let mut tokens = line.split_whitespace().peekable();
+            tokens.peek().is_some() && tokens.all(|t| t.parse::<f64>().is_ok())
+        }
+        Err(_) => false,
+    };
+    if numeric {
+        return false;
+    }
+    match binary_stride(el) {
+        Some(stride) => stride > 0 && el.count.checked_mul(stride).is_some_and(|len| data.len() >= len),
+        None => false,
+    }
+}
+
+type Field = (usize, PlyScalarType);
+
+/// How a vertex stores its shape.
+#[derive(Clone, Debug)]
+enum GeometryFields {
+    /// Scale + rotation quaternion; covariance is `R S² Rᵀ`.
+    ScaleRotation {
+        scale: [Field; 3],
+        /// The first `layout.components()` entries are read.
+        rot: [Field; 4],
+        layout: QuatLayout,
+        /// Every read component is a byte packing `[-1, 1]`: `uchar` as `0..=255`, `char` as
+        /// `-127..=127`.
+        byte_rot: bool,
+    },
+    /// Full 3x3 factor `L` stored row-major as `L_00, L_01, ..., L_22` (`L_rc` = row r,
+    /// column c); covariance is `L Lᵀ`. Used by some research exports (e.g. Cholesky factors).
+    Matrix([Field; 9]),
+}
+
+#[derive(Clone, Debug)]
+enum ColorFields {
+    Rgb([Field; 3]),
+    ShDc([Field; 3]),
+    None,
+}
+
+/// Property index and type of every splat field the decoder reads.
+#[derive(Clone, Debug)]
+struct SplatFields {
+    position: [Field; 3],
+    geometry: GeometryFields,
+    opacity: Field,
+    /// `opacity` is a byte `alpha`/`a` channel, used as the packed alpha whatever the opacity
+    /// encoding.
+    byte_alpha: bool,
+    color: ColorFields,
+    /// Mip-Splatting `filter_3D`, applied only with [`ParseOptionsCore::apply_mip_filter`].
+    mip_filter: Option<Field>,
+    /// `f_rest_0..` in coefficient order, [`sh_rest_len`]`(sh_degree)` of them.
+    sh_rest: Vec<Field>,
+    sh_degree: u32,
+}
+
+impl SplatFields {
+    /// Which property feeds each field, e.g. `position x,y,z; scale_0,... rot (wxyz); ...`.
+    fn describe(&self, el: &PlyElement) -> String {
+        let names = |fields: &[Field]| -> String {
+            fields
+                .iter()
+                .map(|(i, _)| el.properties[*i].name())
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        let geometry = match &self.geometry {
+            GeometryFields::ScaleRotation { scale, rot, layout, byte_rot } => {
+                let rot = names(&rot[..layout.components()]);
+                let layout = match layout {
+                    QuatLayout::Wxyz => "wxyz",
+                    QuatLayout::Xyzw => "xyzw",
+                    QuatLayout::Xyz => "xyz, w rebuilt",
+                };
+                let bytes = if *byte_rot { ", bytes" } else { "" };
+                format!("scale {}; rotation {rot} ({layout}{bytes})", names(scale))
+            }
+            GeometryFields::Matrix(m) => format!("matrix {}", names(m)),
+        };
+        let color = match &self.color {
+            ColorFields::Rgb(c) => format!("rgb {}", names(c)),
+            ColorFields::ShDc(c) => format!("sh dc {}", names(c)),
+            ColorFields::None => "none (white)".to_string(),
+        };
+        let mip = match self.mip_filter {
+            Some(m) => format!("; mip filter {}", names(&[m])),
+            None => String::new(),
+        };
+        let sh = match self.sh_rest.last() {
+            Some(&last) => format!(
+                "; sh degree {} ({}..{})",
+                self.sh_degree,
+                names(&self.sh_rest[..1]),
+                names(&[last])
+            ),
+            None => String::new(),
+        };
+        format!(
+            "position {}; {geometry}; opacity {}{}; color {color}{mip}{sh}",
+            names(&self.position),
+            names(&[self.opacity]),
+            if self.byte_alpha { " (byte alpha)" } else { "" }
+        )
+    }
+}
 
-    let mut pmap: HashMap<String, (usize, PlyScalarType)> = HashMap::new();
+fn property_map(el: &PlyElement) -> HashMap<String, Field> {
+    let mut pmap: HashMap<String, Field> = HashMap::new();
     for (i, p) in el.properties.iter().enumerate() {
         if let PlyProperty::Scalar { name, ty } = p {
             pmap.insert(name.to_lowercase(), (i, *ty));
         }
     }
+    pmap
+}
 
-    let (ix, tx) = pick_name(&pmap, &["x", "pos_x", "position_x"])
-        .ok_or_else(|| PlyError::msg("PLY: missing x in vertex"))?;
-    let (iy, ty_) = pick_name(&pmap, &["y", "pos_y", "position_y"])
-        .ok_or_else(|| PlyError::msg("PLY: missing y in vertex"))?;
-    let (iz, tz) = pick_name(&pmap, &["z", "pos_z", "position_z"])
-        .ok_or_else(|| PlyError::msg("PLY: missing z in vertex"))?;
-
-    let (is0, ts0) = pick_name(&pmap, &["scale_0", "sx", "scale_x", "scalex"])
-        .ok_or_else(|| PlyError::msg("PLY: missing scale_0 in vertex"))?;
-    let (is1, ts1) = pick_name(&pmap, &["scale_1", "sy", "scale_y", "scaley"])
-        .ok_or_else(|| PlyError::msg("PLY: missing scale_1 in vertex"))?;
-    let (is2, ts2) = pick_name(&pmap, &["scale_2", "sz", "scale_z", "scalez"])
-        .ok_or_else(|| PlyError::msg("PLY: missing scale_2 in vertex"))?;
-
-    // Quaternion layout:
-    // - If PLY contains rot_0..rot_3, interpret as (w, x, y, z).
-    // - Otherwise, if it contains qx,qy,qz,qw, interpret as (x, y, z, w).
-    let rot0 = pick_name(&pmap, &["rot_0"]);
-    let rot1 = pick_name(&pmap, &["rot_1"]);
-    let rot2 = pick_name(&pmap, &["rot_2"]);
-    let rot3 = pick_name(&pmap, &["rot_3"]);
-    let qx_f = pick_name(&pmap, &["qx"]);
-    let qy_f = pick_name(&pmap, &["qy"]);
-    let qz_f = pick_name(&pmap, &["qz"]);
-    let qw_f = pick_name(&pmap, &["qw"]);
-
-    let (quat_layout, (ir0, tr0), (ir1, tr1), (ir2, tr2), (ir3, tr3)) = if let (Some(a), Some(b), Some(c), Some(d)) =
-        (rot0, rot1, rot2, rot3)
-    {
-        (QuatLayout::Wxyz, a, b, c, d)
-    } else if let (Some(a), Some(b), Some(c), Some(d)) = (qx_f, qy_f, qz_f, qw_f) {
-        (QuatLayout::Xyzw, a, b, c, d)
+fn resolve_position(pmap: &HashMap<String, Field>, names: &ProfileNames) -> Result<[Field; 3], PlyError> {
+    let req = |names: &[&str], what: &'static str| {
+        pick_name(pmap, names).ok_or_else(|| PlyError::msg(PlyErrorKind::MissingProperty, what))
+    };
+    Ok([
+        req(names.position[0], "PLY: missing x in vertex")?,
+        req(names.position[1], "PLY: missing y in vertex")?,
+        req(names.position[2], "PLY: missing z in vertex")?,
+    ])
+}
+
+/// `quat_layout` forces how the quaternion properties are read; `None` detects it from their
+/// names.
+fn resolve_fields(
+    el: &PlyElement,
+    names: &ProfileNames,
+    quat_layout: Option<QuatLayout>,
+) -> Result<SplatFields, PlyError> {
+    let pmap = property_map(el);
+    let req = |names: &[&str], what: &'static str| {
+        pick_name(&pmap, names).ok_or_else(|| PlyError::msg(PlyErrorKind::MissingProperty, what))
+    };
+    let all = |names: Option<[&str; 4]>| names.and_then(|n| pick_all(&pmap, n));
+
+    let position = resolve_position(&pmap, names)?;
+
+    let matrix: Vec<Field> = L_MATRIX_NAMES.iter().filter_map(|n| pick_name(&pmap, &[n])).collect();
+    let geometry = if let Ok(m) = <[Field; 9]>::try_from(matrix) {
+        GeometryFields::Matrix(m)
     } else {
-        return Err(PlyError::msg(
-            "PLY: missing quaternion fields. Expected either rot_0..rot_3 (wxyz) or qx,qy,qz,qw (xyzw)",
-        ));
+        let scale = [
+            req(names.scale[0], "PLY: missing scale_0 in vertex")?,
+            req(names.scale[1], "PLY: missing scale_1 in vertex")?,
+            req(names.scale[2], "PLY: missing scale_2 in vertex")?,
+        ];
+        // Quaternion layout:
+        // - If PLY contains rot_0..rot_3, interpret as (w, x, y, z).
+        // - Otherwise, if it contains qx,qy,qz,qw, interpret as (x, y, z, w).
+        // - Otherwise, rot_0..rot_2 or qx,qy,qz alone are (x, y, z) with w rebuilt.
+        // A forced layout reads whichever of those sets exists, in name order.
+        // An xyz-only quaternion leaves the fourth slot unread; it repeats x to fill the array.
+        let xyz = |names: Option<[&str; 4]>| {
+            let [x, y, z] = pick_all(&pmap, names.map(|n| [n[0], n[1], n[2]])?)?;
+            Some([x, y, z, x])
+        };
+        let four = || all(names.rot_wxyz).or_else(|| all(names.rot_xyzw));
+        let three = || xyz(names.rot_wxyz).or_else(|| xyz(names.rot_xyzw));
+        let (layout, rot) = if let Some(layout) = quat_layout {
+            let rot = if layout == QuatLayout::Xyz { three() } else { four() };
+            let rot = rot.ok_or_else(|| {
+                PlyError::MsgOwned(
+                    PlyErrorKind::MissingProperty,
+                    format!(
+                        "PLY: quaternion layout {layout:?} needs {} of rot_0..rot_3 or qx,qy,qz,qw",
+                        layout.components()
+                    ),
+                )
+            })?;
+            (layout, rot)
+        } else if let Some(rot) = all(names.rot_wxyz) {
+            (QuatLayout::Wxyz, rot)
+        } else if let Some(q) = all(names.rot_xyzw) {
+            (QuatLayout::Xyzw, q)
+        } else if let Some(q) = xyz(names.rot_wxyz).or_else(|| xyz(names.rot_xyzw)) {
+            (QuatLayout::Xyz, q)
+        } else {
+            return Err(PlyError::msg(
+                PlyErrorKind::MissingProperty,
+                "PLY: missing quaternion fields. Expected either rot_0..rot_3 (wxyz) or qx,qy,qz,qw (xyzw)",
+            ));
+        };
+        let byte_rot = rot[..layout.components()].iter().all(|f| f.1.is_probably_byte_color());
+        GeometryFields::ScaleRotation { scale, rot, layout, byte_rot }
     };
 
-    let (iop, top) = pick_name(&pmap, &["opacity", "alpha", "opac"])
-        .ok_or_else(|| PlyError::msg("PLY: missing opacity in vertex"))?;
+    // An opacity property wins; otherwise a byte `alpha`/`a` channel (RGBA point clouds) is the
+    // alpha itself, and a float `alpha` is read with the opacity encoding like `opacity`.
+    let opacity_names: Vec<&str> = names.opacity.iter().copied().filter(|n| !ALPHA_NAMES.contains(n)).collect();
+    let (opacity, byte_alpha) = match (pick_name(&pmap, &opacity_names), pick_name(&pmap, ALPHA_NAMES)) {
+        (Some(f), _) => (f, false),
+        (None, Some(f)) if f.1.is_probably_byte_color() => (f, true),
+        _ => (req(names.opacity, "PLY: missing opacity in vertex")?, false),
+    };
 
-    let color_r = pick_name(&pmap, &["red", "r"]);
-    let color_g = pick_name(&pmap, &["green", "g"]);
-    let color_b = pick_name(&pmap, &["blue", "b"]);
+    let rgb = names.rgb.map(|rgb| rgb.map(|n| pick_name(&pmap, n)));
+    let dc = DC_NAMES.map(|n| pick_name(&pmap, n));
+    let color = if let Some([Some(r), Some(g), Some(b)]) = rgb {
+        ColorFields::Rgb([r, g, b])
+    } else if let [Some(r), Some(g), Some(b)] = dc {
+        ColorFields::ShDc([r, g, b])
+    } else {
+        ColorFields::None
+    };
 
-    let fdc0 = pick_name(&pmap, &["f_dc_0"]);
-    let fdc1 = pick_name(&pmap, &["f_dc_1"]);
-    let fdc2 = pick_name(&pmap, &["f_dc_2"]);
-    const SH_C0: f32 = 0.28209479177387814;
+    let (sh_degree, sh_rest) = resolve_sh_rest(&pmap);
+
+    Ok(SplatFields {
+        position,
+        geometry,
+        opacity,
+        byte_alpha,
+        color,
+        mip_filter: pick_name(&pmap, MIP_FILTER_NAMES),
+        sh_rest,
+        sh_degree,
+    })
+}
 
-    let count = el.count;
-    let mut center: Vec<f32> = vec![0.0; count * 3];
-    let mut covariance: Vec<f32> = vec![0.0; count * 6];
-    let mut rgba: Vec<u32> = vec![rgba_to_u32(255, 255, 255, 255); count];
+/// Highest SH degree (up to 3) for which all of `f_rest_0 .. f_rest_{sh_rest_len - 1}` exist,
+/// with those fields in coefficient order. Extra `f_rest_*` beyond that degree are ignored.
+fn resolve_sh_rest(pmap: &HashMap<String, Field>) -> (u32, Vec<Field>) {
+    for degree in (1..=3).rev() {
+        let fields: Option<Vec<Field>> = (0..sh_rest_len(degree))
+            .map(|i| pmap.get(&format!("{SH_REST_PREFIX}{i}")).copied())
+            .collect();
+        if let Some(fields) = fields {
+            return (degree, fields);
+        }
+    }
+    (0, Vec::new())
+}
 
-    let mut bbox_min = [f32::INFINITY, f32::INFINITY, f32::INFINITY];
-    let mut bbox_max = [f32::NEG_INFINITY, f32::NEG_INFINITY, f32::NEG_INFINITY];
+/// Random access to the scalar properties of one vertex record.
+trait VertexRow {
+    fn get(&self, field: Field) -> Result<f64, PlyError>;
+}
 
-    match header.format {
-        PlyFormat::BinaryLittleEndian | PlyFormat::BinaryBigEndian => {
-            let little = header.format == PlyFormat::BinaryLittleEndian;
+struct BinaryRow<'a> {
+    bytes: &'a [u8],
+    base: usize,
+    offsets: &'a [usize],
+    little: bool,
+}
 
-            let mut offsets: Vec<usize> = Vec::with_capacity(el.properties.len());
-            let mut stride: usize = 0;
-            for p in el.properties.iter() {
-                offsets.push(stride);
-                let ty = match p {
-                    PlyProperty::Scalar { ty, .. } => *ty,
-                    PlyProperty::List { .. } => unreachable!(),
-                };
-                stride += ty.size_bytes();
-            }
+impl VertexRow for BinaryRow<'_> {
+    fn get(&self, (i, ty): Field) -> Result<f64, PlyError> {
+        read_scalar(self.bytes, self.base + self.offsets[i], ty, self.little)
+    }
+}
 
-            let mut base = header.data_offset;
-            for i in 0..count {
-                let read = |prop_index: usize, t: PlyScalarType| -> Result<f64, PlyError> {
-                    read_scalar(bytes, base + offsets[prop_index], t, little)
-                };
+/// Vertex `index` of a columnar element whose property `i` occupies `columns[i]..`.
+struct ColumnarRow<'a> {
+    bytes: &'a [u8],
+    index: usize,
+    columns: &'a [usize],
+    little: bool,
+}
 
-                let cx = read(ix, tx)? as f32;
-                let cy = read(iy, ty_)? as f32;
-                let cz = read(iz, tz)? as f32;
-
-                bbox_min[0] = bbox_min[0].min(cx);
-                bbox_min[1] = bbox_min[1].min(cy);
-                bbox_min[2] = bbox_min[2].min(cz);
-                bbox_max[0] = bbox_max[0].max(cx);
-                bbox_max[1] = bbox_max[1].max(cy);
-                bbox_max[2] = bbox_max[2].max(cz);
-
-                let mut sx = read(is0, ts0)? as f32;
-                let mut sy = read(is1, ts1)? as f32;
-                let mut sz = read(is2, ts2)? as f32;
-                if assume_log_scale {
-                    sx = sx.exp();
-                    sy = sy.exp();
-                    sz = sz.exp();
-                }
+impl VertexRow for ColumnarRow<'_> {
+    fn get(&self, (i, ty): Field) -> Result<f64, PlyError> {
+        read_scalar(self.bytes, self.columns[i] + self.index * ty.size_bytes(), ty, self.little)
+    }
+}
 
-                let a0 = read(ir0, tr0)? as f32;
-                let a1 = read(ir1, tr1)? as f32;
-                let a2 = read(ir2, tr2)? as f32;
-                let a3 = read(ir3, tr3)? as f32;
-                let (qx, qy, qz, qw) = match quat_layout {
-                    QuatLayout::Wxyz => (a1, a2, a3, a0),
-                    QuatLayout::Xyzw => (a0, a1, a2, a3),
-                };
+struct AsciiRow<'a> {
+    parts: Vec<&'a str>,
+}
 
-                let opv = read(iop, top)? as f32;
-                let alpha = if assume_logit_opacity { sigmoid(opv) } else { opv };
-
-                let cov = covariance_from_quat_scale(qx, qy, qz, qw, sx, sy, sz);
-
-                let v3 = i * 3;
-                center[v3] = cx;
-                center[v3 + 1] = cy;
-                center[v3 + 2] = cz;
-
-                let v6 = i * 6;
-                covariance[v6..v6 + 6].copy_from_slice(&cov);
-
-                let a = clamp255(alpha * 255.0);
-                let mut r = 255u32;
-                let mut g = 255u32;
-                let mut b = 255u32;
-
-                if let (Some((ir, tr)), Some((ig, tg)), Some((ib, tb))) =
-                    (color_r, color_g, color_b)
-                {
-                    let rv = read(ir, tr)? as f32;
-                    let gv = read(ig, tg)? as f32;
-                    let bv = read(ib, tb)? as f32;
-
-                    if tr.is_probably_byte_color()
-                        && tg.is_probably_byte_color()
-                        && tb.is_probably_byte_color()
-                    {
-                        r = clamp255(rv);
-                        g = clamp255(gv);
-                        b = clamp255(bv);
-                    } else {
-                        r = clamp255(rv * 255.0);
-                        g = clamp255(gv * 255.0);
-                        b = clamp255(bv * 255.0);
-                    }
-                } else if let (Some((if0, tf0)), Some((if1, tf1)), Some((if2, tf2))) =
-                    (fdc0, fdc1, fdc2)
-                {
-                    let f0 = read(if0, tf0)? as f32;
-                    let f1 = read(if1, tf1)? as f32;
-                    let f2 = read(if2, tf2)? as f32;
-                    r = clamp255((0.5 + SH_C0 * f0) * 255.0);
-                    g = clamp255((0.5 + SH_C0 * f1) * 255.0);
-                    b = clamp255((0.5 + SH_C0 * f2) * 255.0);
-                }
+impl VertexRow for AsciiRow<'_> {
+    fn get(&self, (i, ty): Field) -> Result<f64, PlyError> {
+        let s = self
+            .parts
+            .get(i)
+            .ok_or_else(|| PlyError::msg(PlyErrorKind::Truncated, "PLY ASCII: missing column"))?;
+        let v = if ty == PlyScalarType::Double {
+            s.parse::<f64>().ok()
+        } else {
+            s.parse::<f32>().ok().map(|v| v as f64)
+        };
+        v.ok_or_else(|| PlyError::msg(PlyErrorKind::InvalidData, "PLY ASCII: failed to parse number"))
+    }
+}
 
-                rgba[i] = rgba_to_u32(r, g, b, a);
-                base += stride;
+struct DecodeParams {
+    scale_base: LogBase,
+    opacity: OpacityMode,
+    /// ASCII files guess float-vs-byte colors per vertex instead of from the declared type.
+    ascii: bool,
+    mip_filter: bool,
+    keep_raw: bool,
+}
+
+impl DecodeParams {
+    /// The resolved [`SplatPlyBuffersCore::scale_mode`] for splats decoded through `fields`.
+    fn scale_mode(&self, fields: &SplatFields) -> Option<ScaleMode> {
+        match fields.geometry {
+            GeometryFields::ScaleRotation { .. } if self.scale_base == LogBase::Linear => Some(ScaleMode::Linear),
+            GeometryFields::ScaleRotation { .. } => Some(ScaleMode::Log),
+            GeometryFields::Matrix(_) => None,
+        }
+    }
+}
+
+struct DecodedSplat {
+    center: [f32; 3],
+    center_f64: [f64; 3],
+    covariance: [f32; 6],
+    rgba: u32,
+    /// RGB color values were scaled from float 0–1 (not read as 0–255).
+    float01_color: bool,
+    /// Linear scale and unit `[x, y, z, w]` rotation, with [`DecodeParams::keep_raw`].
+    scale_quat: Option<([f32; 3], [f32; 4])>,
+}
+
+impl DecodedSplat {
+    fn is_finite(&self) -> bool {
+        let raw = self.scale_quat.iter().flat_map(|(s, q)| s.iter().chain(q));
+        self.center.iter().chain(&self.covariance).chain(raw).all(|v| v.is_finite())
+            && self.center_f64.iter().all(|v| (*v as f32).is_finite())
+    }
+
+    fn zero_non_finite(&mut self) {
+        let zero = |v: &mut f32| {
+            if !v.is_finite() {
+                *v = 0.0;
             }
+        };
+        self.center.iter_mut().chain(&mut self.covariance).for_each(zero);
+        if let Some((s, q)) = self.scale_quat.as_mut() {
+            s.iter_mut().chain(q).for_each(zero);
         }
-        PlyFormat::Ascii => {
-            let data = &bytes[header.data_offset..];
-            let text = core::str::from_utf8(data)
-                .map_err(|_| PlyError::msg("PLY ASCII: data is not valid utf-8"))?;
-            let lines: Vec<&str> = match header.newline {
-                Newline::Lf => text
-                    .split('\n')
-                    .filter(|l| !l.trim().is_empty())
-                    .collect(),
-                Newline::CrLf => text
-                    .split("\r\n")
-                    .filter(|l| !l.trim().is_empty())
-                    .collect(),
-            };
-            if lines.len() < count {
-                return Err(PlyError::msg("PLY ASCII: not enough vertex lines"));
+        for v in &mut self.center_f64 {
+            if !(*v as f32).is_finite() {
+                *v = 0.0;
             }
+        }
+    }
+}
 
-            let mut name_to_col: HashMap<String, usize> = HashMap::new();
-            let mut scalar_i = 0usize;
-            for p in el.properties.iter() {
-                if let PlyProperty::Scalar { name, .. } = p {
-                    name_to_col.insert(name.to_lowercase(), scalar_i);
-                    scalar_i += 1;
-                }
+fn decode_vertex(row: &dyn VertexRow, f: &SplatFields, p: &DecodeParams) -> Result<DecodedSplat, PlyError> {
+    let read = |field: Field| -> Result<f32, PlyError> { Ok(row.get(field)? as f32) };
+
+    let center_f64 = [row.get(f.position[0])?, row.get(f.position[1])?, row.get(f.position[2])?];
+    let center = center_f64.map(|v| v as f32);
+
+    let mut scale_quat = None;
+    let mut covariance = match &f.geometry {
+        GeometryFields::ScaleRotation { scale, rot, layout, byte_rot } => {
+            let sx = p.scale_base.apply(read(scale[0])?);
+            let sy = p.scale_base.apply(read(scale[1])?);
+            let sz = p.scale_base.apply(read(scale[2])?);
+
+            // Packed byte quaternions map onto [-1, 1] before normalization: `uchar` from
+            // 0..=255, `char` from -127..=127 (with -128 clamped).
+            let read_rot = |field: Field| -> Result<f32, PlyError> {
+                let v = read(field)?;
+                Ok(match field.1 {
+                    PlyScalarType::UChar if *byte_rot => v / 255.0 * 2.0 - 1.0,
+                    PlyScalarType::Char if *byte_rot => (v / 127.0).max(-1.0),
+                    _ => v,
+                })
+            };
+            let a0 = read_rot(rot[0])?;
+            let a1 = read_rot(rot[1])?;
+            let a2 = read_rot(rot[2])?;
+            let (qx, qy, qz, qw) = match layout {
+                QuatLayout::Wxyz => (a1, a2, read_rot(rot[3])?, a0),
+                QuatLayout::Xyzw => (a0, a1, a2, read_rot(rot[3])?),
+                QuatLayout::Xyz => (a0, a1, a2, (1.0 - a0 * a0 - a1 * a1 - a2 * a2).max(0.0).sqrt()),
+            };
+            if p.keep_raw {
+                let (x, y, z, w) = normalize_quat(qx, qy, qz, qw);
+                scale_quat = Some(([sx, sy, sz], [x, y, z, w]));
+            }
+            covariance_from_quat_scale(qx, qy, qz, qw, sx, sy, sz)
+        }
+        GeometryFields::Matrix(m) => {
+            let mut l = [0.0f32; 9];
+            for (v, field) in l.iter_mut().zip(m.iter()) {
+                *v = read(*field)?;
             }
+            covariance_from_matrix(&l)
+        }
+    };
 
-            let col = |names: &[&str]| -> Option<usize> {
-                for n in names {
-                    if let Some(v) = name_to_col.get(&n.to_lowercase()) {
-                        return Some(*v);
-                    }
-                }
-                None
-            };
+    let opv = read(f.opacity)?;
+    let mut alpha = match p.opacity {
+        _ if f.byte_alpha => opv / 255.0,
+        OpacityMode::Logit => sigmoid(opv),
+        OpacityMode::Linear => opv,
+        OpacityMode::Scaled255 => opv / 255.0,
+    };
 
-            let cx_c = col(&["x", "pos_x", "position_x"]).ok_or_else(|| PlyError::msg("PLY ASCII: missing x"))?;
-            let cy_c = col(&["y", "pos_y", "position_y"]).ok_or_else(|| PlyError::msg("PLY ASCII: missing y"))?;
-            let cz_c = col(&["z", "pos_z", "position_z"]).ok_or_else(|| PlyError::msg("PLY ASCII: missing z"))?;
-            let s0_c = col(&["scale_0", "sx", "scale_x", "scalex"]).ok_or_else(|| PlyError::msg("PLY ASCII: missing scale_0"))?;
-            let s1_c = col(&["scale_1", "sy", "scale_y", "scaley"]).ok_or_else(|| PlyError::msg("PLY ASCII: missing scale_1"))?;
-            let s2_c = col(&["scale_2", "sz", "scale_z", "scalez"]).ok_or_else(|| PlyError::msg("PLY ASCII: missing scale_2"))?;
-            let r0 = col(&["rot_0"]);
-            let r1 = col(&["rot_1"]);
-            let r2 = col(&["rot_2"]);
-            let r3 = col(&["rot_3"]);
-            let qx = col(&["qx"]);
-            let qy = col(&["qy"]);
-            let qz = col(&["qz"]);
-            let qw = col(&["qw"]);
-
-            let (quat_layout, r0_c, r1_c, r2_c, r3_c) = if let (Some(a), Some(b), Some(c), Some(d)) =
-                (r0, r1, r2, r3)
-            {
-                (QuatLayout::Wxyz, a, b, c, d)
-            } else if let (Some(a), Some(b), Some(c), Some(d)) = (qx, qy, qz, qw) {
-                (QuatLayout::Xyzw, a, b, c, d)
+    if let (true, Some(field)) = (p.mip_filter, f.mip_filter) {
+        let filter = read(field)?;
+        let det_before = covariance_det(&covariance);
+        for k in [0, 3, 5] {
+            covariance[k] += filter * filter;
+        }
+        let det_after = covariance_det(&covariance);
+        if det_after > 0.0 {
+            alpha *= (det_before.max(0.0) / det_after).sqrt();
+        }
+    }
+    let a = clamp255(alpha * 255.0);
+
+    let mut float01_color = false;
+    let (r, g, b) = match &f.color {
+        ColorFields::Rgb([fr, fg, fb]) => {
+            let rv = read(*fr)?;
+            let gv = read(*fg)?;
+            let bv = read(*fb)?;
+            let as_float01 = if p.ascii {
+                rv <= 1.0 && gv <= 1.0 && bv <= 1.0
             } else {
-                return Err(PlyError::msg(
-                    "PLY ASCII: missing quaternion fields. Expected either rot_0..rot_3 (wxyz) or qx,qy,qz,qw (xyzw)",
-                ));
+                !(fr.1.is_probably_byte_color() && fg.1.is_probably_byte_color() && fb.1.is_probably_byte_color())
             };
-            let op_c = col(&["opacity", "alpha", "opac"]).ok_or_else(|| PlyError::msg("PLY ASCII: missing opacity"))?;
-
-            let r_c = col(&["red", "r"]);
-            let g_c = col(&["green", "g"]);
-            let b_c = col(&["blue", "b"]);
-            let f0_c = col(&["f_dc_0"]);
-            let f1_c = col(&["f_dc_1"]);
-            let f2_c = col(&["f_dc_2"]);
-
-            for i in 0..count {
-                let parts: Vec<&str> = lines[i].split_whitespace().collect();
-                let parse = |idx: usize| -> Result<f32, PlyError> {
-                    parts
-                        .get(idx)
-                        .ok_or_else(|| PlyError::msg("PLY ASCII: missing column"))?
-                        .parse::<f32>()
-                        .map_err(|_| PlyError::msg("PLY ASCII: failed to parse number"))
-                };
+            float01_color = as_float01;
+            if as_float01 {
+                (clamp255(rv * 255.0), clamp255(gv * 255.0), clamp255(bv * 255.0))
+            } else {
+                (clamp255(rv), clamp255(gv), clamp255(bv))
+            }
+        }
+        ColorFields::ShDc([f0, f1, f2]) => {
+            let f0 = read(*f0)?;
+            let f1 = read(*f1)?;
+            let f2 = read(*f2)?;
+            (
+                clamp255((0.5 + SH_C0 * f0) * 255.0),
+                clamp255((0.5 + SH_C0 * f1) * 255.0),
+                clamp255((0.5 + SH_C0 * f2) * 255.0),
+            )
+        }
+        ColorFields::None => (255, 255, 255),
+    };
 
-                let cx = parse(cx_c)?;
-                let cy = parse(cy_c)?;
-                let cz = parse(cz_c)?;
-
-                bbox_min[0] = bbox_min[0].min(cx);
-                bbox_min[1] = bbox_min[1].min(cy);
-                bbox_min[2] = bbox_min[2].min(cz);
-                bbox_max[0] = bbox_max[0].max(cx);
-                bbox_max[1] = bbox_max[1].max(cy);
-                bbox_max[2] = bbox_max[2].max(cz);
-
-                let mut sx = parse(s0_c)?;
-                let mut sy = parse(s1_c)?;
-                let mut sz = parse(s2_c)?;
-                if assume_log_scale {
-                    sx = sx.exp();
-                    sy = sy.exp();
-                    sz = sz.exp();
-                }
+    Ok(DecodedSplat {
+        center,
+        center_f64,
+        covariance,
+        rgba: rgba_to_u32(r, g, b, a),
+        float01_color,
+        scale_quat,
+    })
+}
 
-                let a0 = parse(r0_c)?;
-                let a1 = parse(r1_c)?;
-                let a2 = parse(r2_c)?;
-                let a3 = parse(r3_c)?;
-                let (qx, qy, qz, qw) = match quat_layout {
-                    QuatLayout::Wxyz => (a1, a2, a3, a0),
-                    QuatLayout::Xyzw => (a0, a1, a2, a3),
-                };
+/// Collects decoded splats into the output buffers.
+struct SplatAccumulator {
+    center: Vec<f32>,
+    center_f64: Option<Vec<f64>>,
+    covariance: Vec<f32>,
+    rgba: Vec<u32>,
+    sh: Vec<f32>,
+    sh_degree: u32,
+    scales: Vec<f32>,
+    quats: Vec<f32>,
+    min_alpha: u8,
+    nan_policy: Option<NanPolicy>,
+    /// Splats pushed so far, kept or not: the vertex index of the next one.
+    seen: usize,
+    bbox_min: [f32; 3],
+    bbox_max: [f32; 3],
+    centroid: CentroidSum,
+}
 
-                let opv = parse(op_c)?;
-                let alpha = if assume_logit_opacity { sigmoid(opv) } else { opv };
-
-                let cov = covariance_from_quat_scale(qx, qy, qz, qw, sx, sy, sz);
-
-                let v3 = i * 3;
-                center[v3] = cx;
-                center[v3 + 1] = cy;
-                center[v3 + 2] = cz;
-
-                let v6 = i * 6;
-                covariance[v6..v6 + 6].copy_from_slice(&cov);
-
-                let a = clamp255(alpha * 255.0);
-                let mut r = 255u32;
-                let mut g = 255u32;
-                let mut b = 255u32;
-
-                if let (Some(rc), Some(gc), Some(bc)) = (r_c, g_c, b_c) {
-                    let rv = parse(rc)?;
-                    let gv = parse(gc)?;
-                    let bv = parse(bc)?;
-                    let as_float01 = rv <= 1.0 && gv <= 1.0 && bv <= 1.0;
-                    r = clamp255(if as_float01 { rv * 255.0 } else { rv });
-                    g = clamp255(if as_float01 { gv * 255.0 } else { gv });
-                    b = clamp255(if as_float01 { bv * 255.0 } else { bv });
-                } else if let (Some(f0c), Some(f1c), Some(f2c)) = (f0_c, f1_c, f2_c) {
-                    let f0 = parse(f0c)?;
-                    let f1 = parse(f1c)?;
-                    let f2 = parse(f2c)?;
-                    r = clamp255((0.5 + SH_C0 * f0) * 255.0);
-                    g = clamp255((0.5 + SH_C0 * f1) * 255.0);
-                    b = clamp255((0.5 + SH_C0 * f2) * 255.0);
-                }
+impl SplatAccumulator {
+    /// Room for `n` splats with the buffers `opts` asks for. `scales`/`quats` are filled from
+    /// the pushed splats' `scale_quat` either way.
+    fn with_capacity(n: usize, sh_degree: u32, opts: &ParseOptionsCore) -> Self {
+        let raw = if opts.keep_raw { n } else { 0 };
+        SplatAccumulator {
+            center: Vec::with_capacity(n * 3),
+            center_f64: opts.center_f64.then(|| Vec::with_capacity(n * 3)),
+            covariance: Vec::with_capacity(n * 6),
+            rgba: Vec::with_capacity(n),
+            sh: Vec::with_capacity(n * sh_rest_len(sh_degree)),
+            sh_degree,
+            scales: Vec::with_capacity(raw * 3),
+            quats: Vec::with_capacity(raw * 4),
+            min_alpha: opts.min_alpha.unwrap_or(0),
+            nan_policy: opts.nan_policy,
+            seen: 0,
+            bbox_min: [f32::INFINITY; 3],
+            bbox_max: [f32::NEG_INFINITY; 3],
+            centroid: CentroidSum::default(),
+        }
+    }
 
-                rgba[i] = rgba_to_u32(r, g, b, a);
+    /// Appends `s` unless the `nan_policy` skips it or its alpha is below `min_alpha`;
+    /// returns whether it was kept, so the caller knows whether to push its SH too.
+    fn push(&mut self, mut s: DecodedSplat) -> Result<bool, PlyError> {
+        let index = self.seen;
+        self.seen += 1;
+        if let (Some(policy), false) = (self.nan_policy, s.is_finite()) {
+            match policy {
+                NanPolicy::Error => return Err(non_finite_vertex(index)),
+                NanPolicy::Skip => return Ok(false),
+                NanPolicy::Zero => s.zero_non_finite(),
             }
         }
+        if s.rgba >> 24 < self.min_alpha as u32 {
+            return Ok(false);
+        }
+        self.center.extend_from_slice(&s.center);
+        if let Some(c) = self.center_f64.as_mut() {
+            c.extend_from_slice(&s.center_f64);
+        }
+        self.covariance.extend_from_slice(&s.covariance);
+        self.rgba.push(s.rgba);
+        for k in 0..3 {
+            self.bbox_min[k] = self.bbox_min[k].min(s.center[k]);
+            self.bbox_max[k] = self.bbox_max[k].max(s.center[k]);
+        }
+        self.centroid.add(s.center, s.rgba);
+        if let Some((scale, quat)) = s.scale_quat {
+            self.scales.extend_from_slice(&scale);
+            self.quats.extend_from_slice(&quat);
+        }
+        Ok(true)
     }
 
-    Ok(SplatPlyBuffersCore {
-        count: count as u32,
-        format: header.format,
-        center: center.into_boxed_slice(),
-        covariance: covariance.into_boxed_slice(),
-        rgba: rgba.into_boxed_slice(),
-        bbox_min,
-        bbox_max,
+    /// Appends the row's higher-order SH coefficients (none for degree 0), applying the
+    /// `nan_policy` to them: skipping isn't possible once the splat is kept, so `Skip` zeroes
+    /// them like `Zero`.
+    fn push_sh(&mut self, row: &dyn VertexRow, fields: &SplatFields) -> Result<(), PlyError> {
+        for &field in &fields.sh_rest {
+            let v = row.get(field)? as f32;
+            let v = match self.nan_policy {
+                _ if v.is_finite() => v,
+                Some(NanPolicy::Error) => return Err(non_finite_vertex(self.seen - 1)),
+                Some(_) => 0.0,
+                None => v,
+            };
+            self.sh.push(v);
+        }
+        Ok(())
+    }
+
+    fn finish(
+        self,
+        header: &PlyHeaderInfo,
+        format: PlyFormat,
+        el: &PlyElement,
+        scale_mode: Option<ScaleMode>,
+    ) -> SplatPlyBuffersCore {
+        let mut out = SplatPlyBuffersCore {
+            count: self.rgba.len() as u32,
+            format,
+            center: self.center.into_boxed_slice(),
+            covariance: self.covariance.into_boxed_slice(),
+            rgba: self.rgba.into_boxed_slice(),
+            bbox_min: self.bbox_min,
+            bbox_max: self.bbox_max,
+            bsphere_center: [0.0; 3],
+            bsphere_radius: 0.0,
+            centroid: self.centroid.centroid(),
+            center_f64: self.center_f64.map(Vec::into_boxed_slice),
+            sh: self.sh.into_boxed_slice(),
+            sh_degree: self.sh_degree,
+            scales: self.scales.into_boxed_slice(),
+            quats: self.quats.into_boxed_slice(),
+            element_name: el.name.clone(),
+            antialiased: header.antialiased(),
+            scale_mode,
+        };
+        out.recompute_bounding_sphere();
+        out
+    }
+}
+
+/// Running sums for [`SplatPlyBuffersCore::centroid`], in f64 so large scenes don't drift.
+#[derive(Clone, Copy, Default)]
+struct CentroidSum {
+    weighted: [f64; 3],
+    alpha: f64,
+    plain: [f64; 3],
+    count: usize,
+}
+
+impl CentroidSum {
+    fn add(&mut self, center: [f32; 3], rgba: u32) {
+        let alpha = (rgba >> 24) as f64 / 255.0;
+        for (k, &c) in center.iter().enumerate() {
+            self.weighted[k] += alpha * c as f64;
+            self.plain[k] += c as f64;
+        }
+        self.alpha += alpha;
+        self.count += 1;
+    }
+
+    #[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+    fn merge(&mut self, other: &CentroidSum) {
+        for k in 0..3 {
+            self.weighted[k] += other.weighted[k];
+            self.plain[k] += other.plain[k];
+        }
+        self.alpha += other.alpha;
+        self.count += other.count;
+    }
+
+    fn centroid(&self) -> [f32; 3] {
+        if self.alpha > 0.0 {
+            self.weighted.map(|v| (v / self.alpha) as f32)
+        } else if self.count > 0 {
+            self.plain.map(|v| (v / self.count as f64) as f32)
+        } else {
+            [0.0; 3]
+        }
+    }
+}
+
+/// Parses the header and settles the data format, applying the list-property and
+/// mislabeled-ascii checks shared by every vertex decoder.
+fn open_vertex_data(
+    bytes: &[u8],
+    opts: &ParseOptionsCore,
+    trace: &mut Trace,
+) -> Result<(PlyHeaderInfo, PlyFormat), PlyError> {
+    let header = parse_header(bytes)?;
+    trace.emit(|| {
+        let elements: Vec<String> = header.elements.iter().map(|e| format!("{} x{}", e.name, e.count)).collect();
+        format!(
+            "header: {}, elements [{}], data at byte {}",
+            header.format.as_str(),
+            elements.join(", "),
+            header.data_offset
+        )
+    });
+    let el = find_splat_element(&header, opts)?;
+    trace.emit(|| format!("element: {}", el.name));
+
+    let mut format = header.format;
+    if format == PlyFormat::Ascii && looks_like_mislabeled_binary(bytes, &header, el) {
+        if !opts.lenient_format {
+            return Err(PlyError::MsgOwned(
+                PlyErrorKind::InvalidData,
+                format!(
+                    "PLY: header says ascii, but the data does not start with numbers and its {} bytes \
+                     fit {} binary vertices; the format line may be mislabeled \
+                     (enable lenient_format to decode it as binary_little_endian)",
+                    bytes.len() - header.data_offset,
+                    el.count
+                ),
+            ));
+        }
+        format = PlyFormat::BinaryLittleEndian;
+        trace.decide(|| "format: header says ascii but data looks binary; decoding as binary_little_endian".to_string());
+    } else {
+        trace.emit(|| format!("format: {}", format.as_str()));
+    }
+    reject_vertex_lists(el, format)?;
+    // Fixed-size records are checked against the data up front, before any decoder reserves
+    // room for `el.count` splats.
+    if format != PlyFormat::Ascii
+        && let Some(stride) = binary_stride(el)
+    {
+        let little = format == PlyFormat::BinaryLittleEndian;
+        let mut base = header.data_offset;
+        for other in elements_before(&header, el) {
+            base = binary_element_end(bytes, base, other, little)?;
+        }
+        check_binary_len(base, el.count, stride, bytes.len())?;
+    }
+    Ok((header, format))
+}
+
+/// `el.count`, capped at how many records the data after the header could hold at the
+/// smallest possible record size, so list-bearing binary records and ASCII lines (whose
+/// length isn't known before reading them) can't make a decoder over-reserve.
+fn vertex_capacity(bytes: &[u8], header: &PlyHeaderInfo, el: &PlyElement, format: PlyFormat) -> usize {
+    let min_record: usize = match format {
+        // A digit and a separator per property.
+        PlyFormat::Ascii => 2 * el.properties.len(),
+        PlyFormat::BinaryLittleEndian | PlyFormat::BinaryBigEndian => el
+            .properties
+            .iter()
+            .map(|p| match p {
+                PlyProperty::Scalar { ty, .. } => ty.size_bytes(),
+                PlyProperty::List { count_ty, .. } => count_ty.size_bytes(),
+            })
+            .sum(),
+    };
+    el.count.min(bytes.len().saturating_sub(header.data_offset) / min_record.max(1))
+}
+
+/// Binary records with list properties are walked one by one; ASCII lines would need their
+/// columns re-counted per row, which isn't supported.
+fn reject_vertex_lists(el: &PlyElement, format: PlyFormat) -> Result<(), PlyError> {
+    if format == PlyFormat::Ascii && binary_stride(el).is_none() {
+        return Err(PlyError::msg(
+            PlyErrorKind::Unsupported,
+            "PLY: ASCII vertex has list properties — only binary files can skip them",
+        ));
+    }
+    Ok(())
+}
+
+/// Fills `offsets` with the position of each property within the record of `el` starting at
+/// `start`, reading list counts to step over their items, and returns the record length.
+fn list_record_offsets(
+    bytes: &[u8],
+    start: usize,
+    el: &PlyElement,
+    little: bool,
+    offsets: &mut [usize],
+) -> Result<usize, PlyError> {
+    // A list length read from the data can be anything, so every sum is checked.
+    let out_of_bounds = || PlyError::msg(PlyErrorKind::Truncated, "PLY: out of bounds while reading binary data");
+    let mut pos: usize = 0;
+    for (offset, p) in offsets.iter_mut().zip(&el.properties) {
+        *offset = pos;
+        let len = match p {
+            PlyProperty::Scalar { ty, .. } => ty.size_bytes(),
+            PlyProperty::List {
+                count_ty, item_ty, ..
+            } => {
+                let at = start.checked_add(pos).ok_or_else(out_of_bounds)?;
+                let n = read_scalar(bytes, at, *count_ty, little)?;
+                if n < 0.0 {
+                    return Err(PlyError::msg(PlyErrorKind::InvalidData, "PLY: negative list length"));
+                }
+                (n as usize)
+                    .checked_mul(item_ty.size_bytes())
+                    .and_then(|items| items.checked_add(count_ty.size_bytes()))
+                    .ok_or_else(out_of_bounds)?
+            }
+        };
+        pos = pos.checked_add(len).ok_or_else(out_of_bounds)?;
+    }
+    if start.checked_add(pos).is_none_or(|end| end > bytes.len()) {
+        return Err(out_of_bounds());
+    }
+    Ok(pos)
+}
+
+/// Byte offset of each property within a record of the list-free `el`, and the record stride.
+fn scalar_offsets(el: &PlyElement) -> (Vec<usize>, usize) {
+    let mut offsets: Vec<usize> = Vec::with_capacity(el.properties.len());
+    let mut stride: usize = 0;
+    for p in el.properties.iter() {
+        offsets.push(stride);
+        let ty = match p {
+            PlyProperty::Scalar { ty, .. } => *ty,
+            PlyProperty::List { .. } => unreachable!(),
+        };
+        stride += ty.size_bytes();
+    }
+    (offsets, stride)
+}
+
+/// Offset just past the binary data of `el` starting at `start`, which must lie within
+/// `bytes`. Elements with list properties are walked record by record to read each list's
+/// length.
+fn binary_element_end(bytes: &[u8], start: usize, el: &PlyElement, little: bool) -> Result<usize, PlyError> {
+    if let Some(stride) = binary_stride(el) {
+        let required = start as u128 + el.count as u128 * stride as u128;
+        if required > bytes.len() as u128 {
+            return Err(PlyError::MsgOwned(
+                PlyErrorKind::Truncated,
+                format!(
+                    "PLY: element \"{}\" ({} records of {stride} bytes) needs {required} bytes of data, \
+                     but the file has {}",
+                    el.name,
+                    el.count,
+                    bytes.len()
+                ),
+            ));
+        }
+        return Ok(required as usize);
+    }
+    let mut pos = start;
+    let mut offsets = vec![0; el.properties.len()];
+    for _ in 0..el.count {
+        // `list_record_offsets` keeps the record within `bytes`, so this can't overflow.
+        pos += list_record_offsets(bytes, pos, el, little, &mut offsets)?;
+    }
+    Ok(pos)
+}
+
+/// Elements stored before `el`, which have to be skipped to reach its data.
+fn elements_before<'a>(header: &'a PlyHeaderInfo, el: &PlyElement) -> &'a [PlyElement] {
+    let i = header.elements.iter().position(|e| std::ptr::eq(e, el)).unwrap_or(0);
+    &header.elements[..i]
+}
+
+/// ASCII lines (one per record) of the elements before `el`. Counts too large to add up can't
+/// fit in any file, so they report the data as truncated.
+fn ascii_lines_before(header: &PlyHeaderInfo, el: &PlyElement) -> Result<usize, PlyError> {
+    elements_before(header, el)
+        .iter()
+        .try_fold(0usize, |sum, e| sum.checked_add(e.count))
+        .ok_or_else(ascii_lines_missing)
+}
+
+fn ascii_lines_missing() -> PlyError {
+    PlyError::msg(PlyErrorKind::Truncated, "PLY ASCII: not enough vertex lines")
+}
+
+/// Calls `visit` with each of the `el.count` vertex records in `format`. Elements before and
+/// after `vertex` (e.g. `camera`, `range_grid`, `face`) are skipped.
+fn for_each_vertex(
+    bytes: &[u8],
+    header: &PlyHeaderInfo,
+    el: &PlyElement,
+    format: PlyFormat,
+    layout: VertexLayout,
+    visit: impl FnMut(&dyn VertexRow) -> Result<(), PlyError>,
+) -> Result<(), PlyError> {
+    for_each_vertex_upto(bytes, header, el, format, layout, usize::MAX, visit)
+}
+
+/// Like [`for_each_vertex`], stopping after the first `limit` records.
+fn for_each_vertex_upto(
+    bytes: &[u8],
+    header: &PlyHeaderInfo,
+    el: &PlyElement,
+    format: PlyFormat,
+    layout: VertexLayout,
+    limit: usize,
+    mut visit: impl FnMut(&dyn VertexRow) -> Result<(), PlyError>,
+) -> Result<(), PlyError> {
+    let count = el.count;
+    let visited = count.min(limit);
+    match format {
+        PlyFormat::BinaryLittleEndian | PlyFormat::BinaryBigEndian => {
+            let little = format == PlyFormat::BinaryLittleEndian;
+            let mut base = header.data_offset;
+            for other in elements_before(header, el) {
+                base = binary_element_end(bytes, base, other, little)?;
+            }
+            if binary_stride(el).is_none() {
+                if layout == VertexLayout::Columnar {
+                    return Err(PlyError::msg(
+                        PlyErrorKind::Unsupported,
+                        "PLY: columnar vertex data can't have list properties",
+                    ));
+                }
+                // Variable-length records: locate each one's scalars by reading its list counts.
+                let mut offsets = vec![0; el.properties.len()];
+                for _ in 0..visited {
+                    let len = list_record_offsets(bytes, base, el, little, &mut offsets)?;
+                    visit(&BinaryRow {
+                        bytes,
+                        base,
+                        offsets: &offsets,
+                        little,
+                    })?;
+                    base += len;
+                }
+                return Ok(());
+            }
+            let (offsets, stride) = scalar_offsets(el);
+            if layout == VertexLayout::Columnar {
+                check_binary_len(base, count, stride, bytes.len())?;
+                let columns: Vec<usize> = offsets.iter().map(|o| base + o * count).collect();
+                for index in 0..visited {
+                    visit(&ColumnarRow {
+                        bytes,
+                        index,
+                        columns: &columns,
+                        little,
+                    })?;
+                }
+                return Ok(());
+            }
+            check_binary_len(base, visited, stride, bytes.len())?;
+            for _ in 0..visited {
+                let row = BinaryRow {
+                    bytes,
+                    base,
+                    offsets: &offsets,
+                    little,
+                };
+                visit(&row)?;
+                base += stride;
+            }
+        }
+        PlyFormat::Ascii => {
+            let data = &bytes[header.data_offset..];
+            let text = core::str::from_utf8(data)
+                .map_err(|_| PlyError::msg(PlyErrorKind::InvalidData, "PLY ASCII: data is not valid utf-8"))?;
+            let lines: Vec<&str> = match header.newline {
+                Newline::Lf => text
+                    .split('\n')
+                    .filter(|l| !l.trim().is_empty())
+                    .collect(),
+                Newline::CrLf => text
+                    .split("\r\n")
+                    .filter(|l| !l.trim().is_empty())
+                    .collect(),
+            };
+            // One line per record, whatever the element.
+            let skip = ascii_lines_before(header, el)?;
+            if skip.checked_add(count).is_none_or(|needed| lines.len() < needed) {
+                return Err(ascii_lines_missing());
+            }
+
+            for line in lines.iter().skip(skip).take(visited) {
+                let row = AsciiRow {
+                    parts: line.split_whitespace().collect(),
+                };
+                visit(&row)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Beyond this a logit's sigmoid is 1.0 in f32, so larger opacities are not logits.
+pub(crate) const MAX_USEFUL_LOGIT: f64 = 17.0;
+
+/// True if every opacity value is in `[0, 255]` and at least one exceeds
+/// [`MAX_USEFUL_LOGIT`]: a float alpha scaled to 255 rather than a logit or a fraction.
+fn opacity_looks_scaled255(
+    bytes: &[u8],
+    header: &PlyHeaderInfo,
+    el: &PlyElement,
+    format: PlyFormat,
+    layout: VertexLayout,
+    opacity: Field,
+) -> Result<bool, PlyError> {
+    let (mut min, mut max) = (f64::INFINITY, f64::NEG_INFINITY);
+    for_each_vertex(bytes, header, el, format, layout, |row| {
+        let v = row.get(opacity)?;
+        min = min.min(v);
+        max = max.max(v);
+        Ok(())
+    })?;
+    Ok(min >= 0.0 && max > MAX_USEFUL_LOGIT && max <= 255.0)
+}
+
+/// Vertices sampled by [`ScaleMode::Auto`].
+const AUTO_SCALE_SAMPLE: usize = 4096;
+
+/// True if more than half of the scale values in the first [`AUTO_SCALE_SAMPLE`] vertices
+/// are negative. An empty file counts as log, the default encoding.
+fn scales_look_log(
+    bytes: &[u8],
+    header: &PlyHeaderInfo,
+    el: &PlyElement,
+    format: PlyFormat,
+    layout: VertexLayout,
+    scale: [Field; 3],
+) -> Result<bool, PlyError> {
+    let (mut negative, mut total) = (0usize, 0usize);
+    for_each_vertex_upto(bytes, header, el, format, layout, AUTO_SCALE_SAMPLE, |row| {
+        for field in scale {
+            negative += (row.get(field)? < 0.0) as usize;
+            total += 1;
+        }
+        Ok(())
+    })?;
+    Ok(total == 0 || 2 * negative > total)
+}
+
+fn non_finite_vertex(index: usize) -> PlyError {
+    PlyError::MsgOwned(PlyErrorKind::InvalidData, format!("PLY: non-finite value in vertex {index}"))
+}
+
+/// Resolves the encodings to decode with. `looks_scaled255` runs the 0–255 opacity detection
+/// and is only called when the options leave it to detection; `looks_log_scale` likewise
+/// runs the [`ScaleMode::Auto`] detection.
+fn decode_params(
+    opts: &ParseOptionsCore,
+    header: &PlyHeaderInfo,
+    format: PlyFormat,
+    looks_scaled255: impl FnOnce() -> Result<bool, PlyError>,
+    looks_log_scale: impl FnOnce() -> Result<bool, PlyError>,
+) -> Result<DecodeParams, PlyError> {
+    let (_, assume_logit_opacity) = opts.encoding();
+    let opacity = match opts.opacity_mode {
+        Some(mode) => mode,
+        None if opts.exporter_profile == ExporterProfile::Generic && looks_scaled255()? => OpacityMode::Scaled255,
+        None if assume_logit_opacity => OpacityMode::Logit,
+        None => OpacityMode::Linear,
+    };
+    Ok(DecodeParams {
+        scale_base: match opts.scale_mode {
+            Some(ScaleMode::Auto) if !looks_log_scale()? => LogBase::Linear,
+            Some(ScaleMode::Auto) => opts.scale_log_base,
+            _ => opts.scale_base(),
+        },
+        opacity,
+        ascii: format == PlyFormat::Ascii,
+        mip_filter: opts.apply_mip_filter && !header.antialiased(),
+        keep_raw: opts.keep_raw,
+    })
+}
+
+/// Reports how [`decode_params`] and the resolved fields make the decoder read scales,
+/// opacity and colors. ASCII float-vs-byte colors are guessed per vertex and reported after
+/// the decode instead.
+fn trace_decisions(fields: &SplatFields, opts: &ParseOptionsCore, params: &DecodeParams, trace: &mut Trace) {
+    let source = |flag: &str| match opts.exporter_profile {
+        ExporterProfile::Generic => flag.to_string(),
+        profile => format!("{profile:?} profile"),
+    };
+    if let GeometryFields::ScaleRotation { byte_rot, .. } = fields.geometry {
+        if byte_rot {
+            trace.decide(|| {
+                "rotation: byte components, uchar 0..=255 or char -127..=127 remapped to [-1, 1]".to_string()
+            });
+        }
+        trace.decide(|| {
+            let why = match opts.scale_mode {
+                Some(ScaleMode::Auto) => {
+                    format!("scale_mode = Auto, from the signs of up to {AUTO_SCALE_SAMPLE} vertices' scales")
+                }
+                Some(mode) => format!("scale_mode = {mode:?}"),
+                None => source(if opts.encoding().0 { "assume_log_scale" } else { "assume_log_scale = false" }),
+            };
+            match params.scale_base {
+                LogBase::Linear => format!("scales: read as linear ({why})"),
+                base => format!("scales: read as {base:?} logarithms and exponentiated ({why})"),
+            }
+        });
+    }
+    trace.decide(|| match (opts.opacity_mode, params.opacity) {
+        _ if fields.byte_alpha => "opacity: no opacity property; the byte alpha channel is the 0–255 alpha".to_string(),
+        (Some(mode), _) => format!("opacity: read as {mode:?} (set by opacity_mode)"),
+        (None, OpacityMode::Scaled255) => {
+            "opacity: values lie in [0, 255] and some exceed any useful logit; read as 0–255 alpha".to_string()
+        }
+        (None, OpacityMode::Logit) => {
+            format!("opacity: read as a logit through a sigmoid ({})", source("assume_logit_opacity"))
+        }
+        (None, OpacityMode::Linear) => {
+            format!("opacity: read as linear 0–1 ({})", source("assume_logit_opacity = false"))
+        }
+    });
+    match &fields.color {
+        ColorFields::Rgb(c) if !params.ascii => trace.decide(|| {
+            let bytes = c.iter().all(|f| f.1.is_probably_byte_color());
+            format!(
+                "colors: read as {} because red/green/blue are declared {}",
+                if bytes { "0–255" } else { "float 0–1" },
+                c.map(|f| f.1.as_str()).join("/")
+            )
+        }),
+        ColorFields::Rgb(_) => {}
+        ColorFields::ShDc(_) => trace.decide(|| "colors: from the SH DC coefficients".to_string()),
+        ColorFields::None => trace.decide(|| "colors: no color properties; splats are white".to_string()),
+    }
+}
+
+fn decode_splats(bytes: &[u8], opts: &ParseOptionsCore, trace: &mut Trace) -> Result<SplatPlyBuffersCore, PlyError> {
+    let (header, format) = open_vertex_data(bytes, opts, trace)?;
+    if is_supersplat_compressed(&header) {
+        return decode_supersplat(bytes, &header, format, opts, trace);
+    }
+    let el = find_splat_element(&header, opts)?;
+    let fields = resolve_fields(el, &opts.exporter_profile.names(), opts.quat_layout_override)?;
+    decode_with_fields(bytes, &header, format, el, &fields, opts, trace)
+}
+
+/// Decodes the vertices of `el` once its fields are resolved.
+fn decode_with_fields(
+    bytes: &[u8],
+    header: &PlyHeaderInfo,
+    format: PlyFormat,
+    el: &PlyElement,
+    fields: &SplatFields,
+    opts: &ParseOptionsCore,
+    trace: &mut Trace,
+) -> Result<SplatPlyBuffersCore, PlyError> {
+    trace.emit(|| format!("fields: {}", fields.describe(el)));
+    let layout = opts.vertex_layout.or(header.vertex_layout_hint()).unwrap_or_default();
+    trace.emit(|| format!("layout: {layout:?}"));
+
+    let params = decode_params(
+        opts,
+        header,
+        format,
+        || Ok(!fields.byte_alpha && opacity_looks_scaled255(bytes, header, el, format, layout, fields.opacity)?),
+        || match &fields.geometry {
+            GeometryFields::ScaleRotation { scale, .. } => scales_look_log(bytes, header, el, format, layout, *scale),
+            GeometryFields::Matrix(_) => Ok(true),
+        },
+    )?;
+    trace.emit(|| {
+        format!(
+            "decode: {} vertices (scale base: {:?}, opacity: {:?})",
+            el.count, params.scale_base, params.opacity
+        )
+    });
+    trace_decisions(fields, opts, &params, trace);
+    if opts.apply_mip_filter && fields.mip_filter.is_some() && header.antialiased() {
+        trace.decide(|| "mip filter: skipped, the header marks the file antialiased".to_string());
+    }
+    #[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+    if parallel_decode_applies(format, layout, el, opts) {
+        trace.progress(0, el.count);
+        let out = decode_binary_parallel(bytes, header, el, format, fields, opts, &params)?;
+        trace.progress(el.count, el.count);
+        trace.emit(|| format!("decode: done, {} splats (parallel)", out.count));
+        return Ok(out);
+    }
+    let capacity = vertex_capacity(bytes, header, el, format);
+    let mut acc = SplatAccumulator::with_capacity(capacity, fields.sh_degree, opts);
+    let mut float01_colors = 0usize;
+    let mut done = 0;
+    for_each_vertex(bytes, header, el, format, layout, |row| {
+        trace.progress(done, el.count);
+        done += 1;
+        let splat = decode_vertex(row, fields, &params)?;
+        let float01_color = splat.float01_color;
+        if !acc.push(splat)? {
+            return Ok(());
+        }
+        float01_colors += float01_color as usize;
+        acc.push_sh(row, fields)
+    })?;
+    trace.progress(el.count, el.count);
+    let out = acc.finish(header, format, el, params.scale_mode(fields));
+    if let Some(min) = opts.min_alpha {
+        trace.emit(|| format!("min_alpha: kept {} of {} splats with alpha >= {min}", out.count, el.count));
+    }
+    if params.ascii && matches!(fields.color, ColorFields::Rgb(_)) {
+        trace.decide(|| {
+            format!(
+                "colors: ascii values guessed per vertex: {float01_colors} read as float 0–1 \
+                 (every channel <= 1.0), {} as 0–255",
+                out.count as usize - float01_colors
+            )
+        });
+    }
+    trace.emit(|| format!("decode: done, {} splats", out.count));
+    Ok(out)
+}
+
+/// Vertices each task of the parallel decode handles.
+#[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+const PARALLEL_CHUNK: usize = 16 * 1024;
+
+/// Fixed-stride interleaved binary data decodes in parallel unless a filter drops vertices,
+/// which would shift the output index of every later splat.
+#[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+fn parallel_decode_applies(
+    format: PlyFormat,
+    layout: VertexLayout,
+    el: &PlyElement,
+    opts: &ParseOptionsCore,
+) -> bool {
+    format != PlyFormat::Ascii
+        && layout == VertexLayout::Interleaved
+        && binary_stride(el).is_some()
+        && opts.min_alpha.unwrap_or(0) == 0
+        && opts.nan_policy != Some(NanPolicy::Skip)
+}
+
+/// The binary decode of [`decode_with_fields`] split over rayon tasks: every splat is written
+/// at its vertex index into preallocated buffers, and the per-chunk bboxes are merged.
+#[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+fn decode_binary_parallel(
+    bytes: &[u8],
+    header: &PlyHeaderInfo,
+    el: &PlyElement,
+    format: PlyFormat,
+    fields: &SplatFields,
+    opts: &ParseOptionsCore,
+    params: &DecodeParams,
+) -> Result<SplatPlyBuffersCore, PlyError> {
+    use rayon::prelude::*;
+
+    /// `buf` cut into `width * PARALLEL_CHUNK` pieces, or `chunks` empty slices if it is empty.
+    fn split<T>(buf: &mut [T], width: usize, chunks: usize) -> Vec<&mut [T]> {
+        if buf.is_empty() {
+            return (0..chunks).map(|_| <&mut [T]>::default()).collect();
+        }
+        buf.chunks_mut(width * PARALLEL_CHUNK).collect()
+    }
+
+    let n = el.count;
+    let little = format == PlyFormat::BinaryLittleEndian;
+    let (offsets, stride) = scalar_offsets(el);
+    let mut base = header.data_offset;
+    for other in elements_before(header, el) {
+        base = binary_element_end(bytes, base, other, little)?;
+    }
+    check_binary_len(base, n, stride, bytes.len())?;
+
+    let sh_len = fields.sh_rest.len();
+    let raw = opts.keep_raw && matches!(fields.geometry, GeometryFields::ScaleRotation { .. });
+    let mut center = vec![0.0f32; n * 3];
+    let mut center_f64 = opts.center_f64.then(|| vec![0.0f64; n * 3]);
+    let mut covariance = vec![0.0f32; n * 6];
+    let mut rgba = vec![0u32; n];
+    let mut sh = vec![0.0f32; n * sh_len];
+    let mut scales = vec![0.0f32; if raw { n * 3 } else { 0 }];
+    let mut quats = vec![0.0f32; if raw { n * 4 } else { 0 }];
+
+    let chunks = n.div_ceil(PARALLEL_CHUNK);
+    let tasks: Vec<_> = split(&mut center, 3, chunks)
+        .into_iter()
+        .zip(split(&mut covariance, 6, chunks))
+        .zip(split(&mut rgba, 1, chunks))
+        .zip(split(&mut sh, sh_len, chunks))
+        .zip(split(&mut scales, 3, chunks))
+        .zip(split(&mut quats, 4, chunks))
+        .zip(split(center_f64.as_deref_mut().unwrap_or_default(), 3, chunks))
+        .enumerate()
+        .collect();
+
+    let bounds: Vec<Result<_, PlyError>> = tasks
+        .into_par_iter()
+        .map(|(chunk, ((((((center, covariance), rgba), sh), scales), quats), center_f64))| {
+            let start = chunk * PARALLEL_CHUNK;
+            let mut bbox_min = [f32::INFINITY; 3];
+            let mut bbox_max = [f32::NEG_INFINITY; 3];
+            let mut sum = CentroidSum::default();
+            for (i, rgba) in rgba.iter_mut().enumerate() {
+                let index = start + i;
+                let row = BinaryRow {
+                    bytes,
+                    base: base + index * stride,
+                    offsets: &offsets,
+                    little,
+                };
+                let mut s = decode_vertex(&row, fields, params)?;
+                if let (Some(policy), false) = (opts.nan_policy, s.is_finite()) {
+                    match policy {
+                        NanPolicy::Error => return Err(non_finite_vertex(index)),
+                        // `Skip` never gets here; see `parallel_decode_applies`.
+                        NanPolicy::Skip | NanPolicy::Zero => s.zero_non_finite(),
+                    }
+                }
+                for k in 0..3 {
+                    bbox_min[k] = bbox_min[k].min(s.center[k]);
+                    bbox_max[k] = bbox_max[k].max(s.center[k]);
+                }
+                sum.add(s.center, s.rgba);
+                center[i * 3..i * 3 + 3].copy_from_slice(&s.center);
+                if !center_f64.is_empty() {
+                    center_f64[i * 3..i * 3 + 3].copy_from_slice(&s.center_f64);
+                }
+                covariance[i * 6..i * 6 + 6].copy_from_slice(&s.covariance);
+                *rgba = s.rgba;
+                if let (Some((scale, quat)), true) = (s.scale_quat, raw) {
+                    scales[i * 3..i * 3 + 3].copy_from_slice(&scale);
+                    quats[i * 4..i * 4 + 4].copy_from_slice(&quat);
+                }
+                for (v, &field) in sh[i * sh_len..(i + 1) * sh_len].iter_mut().zip(&fields.sh_rest) {
+                    let x = row.get(field)? as f32;
+                    *v = match opts.nan_policy {
+                        _ if x.is_finite() => x,
+                        Some(NanPolicy::Error) => return Err(non_finite_vertex(index)),
+                        Some(_) => 0.0,
+                        None => x,
+                    };
+                }
+            }
+            Ok((bbox_min, bbox_max, sum))
+        })
+        .collect();
+
+    let mut bbox_min = [f32::INFINITY; 3];
+    let mut bbox_max = [f32::NEG_INFINITY; 3];
+    let mut centroid = CentroidSum::default();
+    for chunk in bounds {
+        let (lo, hi, sum) = chunk?;
+        for k in 0..3 {
+            bbox_min[k] = bbox_min[k].min(lo[k]);
+            bbox_max[k] = bbox_max[k].max(hi[k]);
+        }
+        centroid.merge(&sum);
+    }
+    let mut out = SplatPlyBuffersCore {
+        count: n as u32,
+        format,
+        center: center.into_boxed_slice(),
+        covariance: covariance.into_boxed_slice(),
+        rgba: rgba.into_boxed_slice(),
+        bbox_min,
+        bbox_max,
+        bsphere_center: [0.0; 3],
+        bsphere_radius: 0.0,
+        centroid: centroid.centroid(),
+        center_f64: center_f64.map(Vec::into_boxed_slice),
+        sh: sh.into_boxed_slice(),
+        sh_degree: fields.sh_degree,
+        scales: scales.into_boxed_slice(),
+        quats: quats.into_boxed_slice(),
+        element_name: el.name.clone(),
+        antialiased: header.antialiased(),
+        scale_mode: params.scale_mode(fields),
+    };
+    out.recompute_bounding_sphere();
+    Ok(out)
+}
+
+/// Vertices covered by each `chunk` record of a SuperSplat compressed PLY.
+const SUPERSPLAT_CHUNK_SIZE: usize = 256;
+
+/// Packed `uint` vertex properties of a SuperSplat compressed PLY.
+const SUPERSPLAT_PACKED_NAMES: [&str; 4] = ["packed_position", "packed_rotation", "packed_scale", "packed_color"];
+
+/// `chunk` properties holding the position and (log) scale ranges, `min`s then `max`es.
+const SUPERSPLAT_CHUNK_NAMES: [&str; 12] = [
+    "min_x", "min_y", "min_z", "max_x", "max_y", "max_z", //
+    "min_scale_x", "min_scale_y", "min_scale_z", "max_scale_x", "max_scale_y", "max_scale_z",
+];
+
+/// Optional `chunk` color ranges written by newer SuperSplat versions.
+const SUPERSPLAT_CHUNK_COLOR_NAMES: [&str; 6] = ["min_r", "min_g", "min_b", "max_r", "max_g", "max_b"];
+
+/// True for the PlayCanvas SuperSplat compressed layout: a `chunk` element plus `uint`
+/// `packed_*` vertex properties.
+fn is_supersplat_compressed(header: &PlyHeaderInfo) -> bool {
+    let has_chunk = header.elements.iter().any(|e| e.name.eq_ignore_ascii_case("chunk"));
+    has_chunk
+        && find_vertex_element(header).is_ok_and(|el| {
+            let pmap = property_map(el);
+            SUPERSPLAT_PACKED_NAMES
+                .iter()
+                .all(|n| pmap.get(*n).is_some_and(|f| f.1 == PlyScalarType::UInt))
+        })
+}
+
+/// The `bits`-wide field of `v` starting at bit `shift`, normalized to [0, 1].
+fn unpack_unorm(v: u32, shift: u32, bits: u32) -> f32 {
+    let max = (1u32 << bits) - 1;
+    ((v >> shift) & max) as f32 / max as f32
+}
+
+/// 11/10/11-bit normalized xyz, x in the high bits.
+fn unpack_111011(v: u32) -> [f32; 3] {
+    [unpack_unorm(v, 21, 11), unpack_unorm(v, 11, 10), unpack_unorm(v, 0, 11)]
+}
+
+/// "Smallest three" quaternion: the top 2 bits index the largest component (in x, y, z, w
+/// order), rebuilt from the unit norm; the other three follow in order, 10 bits each, mapped
+/// from [0, 1] to [-1/√2, 1/√2]. Returns (x, y, z, w).
+fn unpack_smallest_three(v: u32) -> [f32; 4] {
+    let c = |shift: u32| (unpack_unorm(v, shift, 10) - 0.5) * std::f32::consts::SQRT_2;
+    let (a, b, d) = (c(20), c(10), c(0));
+    let m = (1.0 - (a * a + b * b + d * d)).max(0.0).sqrt();
+    match v >> 30 {
+        0 => [m, a, b, d],
+        1 => [a, m, b, d],
+        2 => [a, b, m, d],
+        _ => [a, b, d, m],
+    }
+}
+
+/// Higher-order SH coefficient from a SuperSplat `sh` element byte.
+fn unpack_supersplat_sh(n: f64) -> f32 {
+    let t = match n as u32 {
+        0 => 0.0,
+        255 => 1.0,
+        n => (n as f32 + 0.5) / 256.0,
+    };
+    (t - 0.5) * 8.0
+}
+
+/// Decodes the PlayCanvas SuperSplat compressed layout. Each run of
+/// [`SUPERSPLAT_CHUNK_SIZE`] vertices shares a `chunk` record with the min/max of its
+/// positions and log scales (and, in newer files, colors); vertices store 11/10/11-bit
+/// positions and log scales normalized to those ranges, a smallest-three quaternion, and
+/// 8-bit color and linear opacity, where the color is the SH DC color `0.5 + C0·f_dc`.
+/// An optional `sh` element holds the higher-order coefficients as bytes.
+///
+/// The encodings are fixed by the format, so the scale, opacity and mip-filter options don't
+/// apply; `keep_raw` does.
+fn decode_supersplat(
+    bytes: &[u8],
+    header: &PlyHeaderInfo,
+    format: PlyFormat,
+    opts: &ParseOptionsCore,
+    trace: &mut Trace,
+) -> Result<SplatPlyBuffersCore, PlyError> {
+    if format == PlyFormat::Ascii {
+        return Err(PlyError::msg(PlyErrorKind::Unsupported, "PLY: SuperSplat compressed data must be binary"));
+    }
+    let little = format == PlyFormat::BinaryLittleEndian;
+    let mut starts = Vec::with_capacity(header.elements.len());
+    let mut pos = header.data_offset;
+    for el in &header.elements {
+        starts.push(pos);
+        pos = binary_element_end(bytes, pos, el, little)?;
+    }
+    let element = |name: &str| header.elements.iter().position(|e| e.name.eq_ignore_ascii_case(name));
+    // Row `j` of element `i`, which must have no list properties.
+    let rows = |i: usize| -> Result<(usize, Vec<usize>, usize), PlyError> {
+        let el = &header.elements[i];
+        if binary_stride(el).is_none() {
+            return Err(PlyError::MsgOwned(
+                PlyErrorKind::Unsupported,
+                format!("PLY: SuperSplat element \"{}\" has list properties", el.name),
+            ));
+        }
+        let (offsets, stride) = scalar_offsets(el);
+        Ok((starts[i], offsets, stride))
+    };
+    fn row<'a>(bytes: &'a [u8], rows: &'a (usize, Vec<usize>, usize), j: usize, little: bool) -> BinaryRow<'a> {
+        let (start, offsets, stride) = rows;
+        BinaryRow {
+            bytes,
+            base: start + j * stride,
+            offsets,
+            little,
+        }
+    }
+
+    let chunk_i = element("chunk")
+        .ok_or_else(|| PlyError::msg(PlyErrorKind::MissingElement, "PLY: element \"chunk\" not found"))?;
+    let chunk_el = &header.elements[chunk_i];
+    let chunk_map = property_map(chunk_el);
+    let chunk_fields = SUPERSPLAT_CHUNK_NAMES
+        .iter()
+        .map(|n| {
+            chunk_map
+                .get(*n)
+                .copied()
+                .ok_or_else(|| {
+                    PlyError::MsgOwned(PlyErrorKind::MissingProperty, format!("PLY: SuperSplat chunk is missing {n}"))
+                })
+        })
+        .collect::<Result<Vec<Field>, PlyError>>()?;
+    let color_fields: Option<Vec<Field>> =
+        SUPERSPLAT_CHUNK_COLOR_NAMES.iter().map(|n| chunk_map.get(*n).copied()).collect();
+    let chunk_rows = rows(chunk_i)?;
+    let chunks = (0..chunk_el.count)
+        .map(|j| {
+            let r = row(bytes, &chunk_rows, j, little);
+            let mut ranges = [0.0f32; 18];
+            for (v, &f) in ranges.iter_mut().zip(chunk_fields.iter().chain(color_fields.iter().flatten())) {
+                *v = r.get(f)? as f32;
+            }
+            if color_fields.is_none() {
+                ranges[12..].copy_from_slice(&[0.0, 0.0, 0.0, 1.0, 1.0, 1.0]);
+            }
+            Ok(ranges)
+        })
+        .collect::<Result<Vec<[f32; 18]>, PlyError>>()?;
+
+    let vertex_i = element("vertex")
+        .ok_or_else(|| PlyError::msg(PlyErrorKind::MissingElement, "PLY: element \"vertex\" not found"))?;
+    let vertex_el = &header.elements[vertex_i];
+    if vertex_el.count.div_ceil(SUPERSPLAT_CHUNK_SIZE) > chunks.len() {
+        return Err(PlyError::MsgOwned(
+            PlyErrorKind::Truncated,
+            format!(
+                "PLY: {} SuperSplat chunks cover fewer than the {} vertices",
+                chunks.len(),
+                vertex_el.count
+            ),
+        ));
+    }
+    let vertex_map = property_map(vertex_el);
+    let [packed_position, packed_rotation, packed_scale, packed_color] = SUPERSPLAT_PACKED_NAMES.map(|n| vertex_map[n]);
+    let vertex_rows = rows(vertex_i)?;
+    check_binary_len(vertex_rows.0, vertex_el.count, vertex_rows.2, bytes.len())?;
+
+    let sh = match element("sh") {
+        Some(i) if header.elements[i].count == vertex_el.count => {
+            let (degree, fields) = resolve_sh_rest(&property_map(&header.elements[i]));
+            Some((rows(i)?, degree, fields))
+        }
+        Some(_) => return Err(PlyError::msg(PlyErrorKind::InvalidData, "PLY: SuperSplat sh and vertex counts differ")),
+        None => None,
+    };
+    let sh_degree = sh.as_ref().map_or(0, |s| s.1);
+    trace.decide(|| {
+        format!(
+            "layout: SuperSplat compressed, {} chunks, SH degree {sh_degree}; dequantizing packed properties",
+            chunks.len()
+        )
+    });
+
+    let lerp = |lo: f32, hi: f32, t: f32| lo + (hi - lo) * t;
+    let mut acc = SplatAccumulator::with_capacity(vertex_el.count, sh_degree, opts);
+    for j in 0..vertex_el.count {
+        trace.progress(j, vertex_el.count);
+        let ranges = &chunks[j / SUPERSPLAT_CHUNK_SIZE];
+        let r = row(bytes, &vertex_rows, j, little);
+        let packed = |f: Field| -> Result<u32, PlyError> { Ok(r.get(f)? as u32) };
+
+        let t = unpack_111011(packed(packed_position)?);
+        let center: [f32; 3] = std::array::from_fn(|k| lerp(ranges[k], ranges[3 + k], t[k]));
+        let t = unpack_111011(packed(packed_scale)?);
+        let [sx, sy, sz]: [f32; 3] = std::array::from_fn(|k| lerp(ranges[6 + k], ranges[9 + k], t[k]).exp());
+        let [qx, qy, qz, qw] = unpack_smallest_three(packed(packed_rotation)?);
+        let color = packed(packed_color)?;
+        let [r8, g8, b8] = [0, 1, 2].map(|k| {
+            let t = unpack_unorm(color, 24 - 8 * k as u32, 8);
+            clamp255(lerp(ranges[12 + k], ranges[15 + k], t) * 255.0)
+        });
+        let a8 = clamp255(unpack_unorm(color, 0, 8) * 255.0);
+
+        let kept = acc.push(DecodedSplat {
+            center,
+            center_f64: center.map(|v| v as f64),
+            covariance: covariance_from_quat_scale(qx, qy, qz, qw, sx, sy, sz),
+            rgba: rgba_to_u32(r8, g8, b8, a8),
+            float01_color: false,
+            scale_quat: opts.keep_raw.then(|| {
+                let (x, y, z, w) = normalize_quat(qx, qy, qz, qw);
+                ([sx, sy, sz], [x, y, z, w])
+            }),
+        })?;
+        if !kept {
+            continue;
+        }
+        if let Some((sh_rows, _, fields)) = &sh {
+            let r = row(bytes, sh_rows, j, little);
+            for &f in fields {
+                acc.sh.push(unpack_supersplat_sh(r.get(f)?));
+            }
+        }
+    }
+    trace.progress(vertex_el.count, vertex_el.count);
+    let out = acc.finish(header, format, vertex_el, Some(ScaleMode::Log));
+    if let Some(min) = opts.min_alpha {
+        trace.emit(|| format!("min_alpha: kept {} of {} splats with alpha >= {min}", out.count, vertex_el.count));
+    }
+    trace.emit(|| format!("decode: done, {} splats", out.count));
+    Ok(out)
+}
+
+/// Splat field resolution for one vertex layout, reusable across files that share it (e.g.
+/// the frames of a capture) so [`parse_splat_ply_core_with_schema`] can skip resolving the
+/// fields again. Resolved with the [`ExporterProfile::Generic`] names.
+#[derive(Debug, Clone)]
+pub struct SplatSchema {
+    /// Vertex properties (names and types, in order) the schema was resolved from.
+    properties: Vec<PlyProperty>,
+    fields: SplatFields,
+}
+
+impl SplatSchema {
+    /// Resolves the splat fields of `header`'s vertex element. Fails like a parse would if
+    /// required fields are missing.
+    pub fn from_header(header: &PlyHeaderInfo) -> Result<SplatSchema, PlyError> {
+        let el = find_vertex_element(header)?;
+        reject_vertex_lists(el, header.format)?;
+        Ok(SplatSchema {
+            properties: el.properties.clone(),
+            fields: resolve_fields(el, &ExporterProfile::Generic.names(), None)?,
+        })
+    }
+
+    /// True if `header`'s vertex element has exactly the properties (names, types and order)
+    /// the schema was built from. Vertex counts and other elements may differ.
+    pub fn matches(&self, header: &PlyHeaderInfo) -> bool {
+        find_vertex_element(header).is_ok_and(|el| el.properties == self.properties)
+    }
+}
+
+/// Like [`parse_splat_ply_core_with_options`], but takes the field resolution from `schema`
+/// instead of redoing it. Fails if the file's vertex properties differ from the schema's
+/// (see [`SplatSchema::matches`]). `opts.exporter_profile` still picks the encodings, but
+/// not the field names; `opts.quat_layout_override` re-resolves the fields with that layout.
+pub fn parse_splat_ply_core_with_schema(
+    bytes: &[u8],
+    schema: &SplatSchema,
+    opts: &ParseOptionsCore,
+) -> Result<SplatPlyBuffersCore, PlyError> {
+    let mut trace = Trace::new(None);
+    let (header, format) = open_vertex_data(bytes, opts, &mut trace)?;
+    let el = find_splat_element(&header, opts)?;
+    if el.properties != schema.properties {
+        return Err(PlyError::msg(
+            PlyErrorKind::InvalidArgument,
+            "PLY: vertex properties differ from the schema; build a new SplatSchema for this file",
+        ));
+    }
+    let forced;
+    let fields = match opts.quat_layout_override {
+        Some(layout) => {
+            forced = resolve_fields(el, &ExporterProfile::Generic.names(), Some(layout))?;
+            &forced
+        }
+        None => &schema.fields,
+    };
+    let out = decode_with_fields(bytes, &header, format, el, fields, opts, &mut trace)?;
+    Ok(postprocess(out, opts, &mut trace))
+}
+
+/// Upper bound on the header size [`parse_splat_ply_reader`] will buffer.
+const MAX_STREAM_HEADER_BYTES: usize = 1 << 20;
+
+/// Splats [`parse_splat_ply_reader`] reserves room for before reading any records.
+const STREAM_RESERVE_SPLATS: usize = 1 << 16;
+
+/// Reads the header from the start of `reader` through its `end_header` line, leaving the
+/// reader at the first data byte. Fails past [`MAX_STREAM_HEADER_BYTES`], so a stream with no
+/// `end_header` (or no newlines at all) can't be buffered without bound.
+pub(crate) fn read_header_bytes<R: std::io::BufRead>(reader: &mut R) -> Result<Vec<u8>, PlyError> {
+    use std::io::{BufRead, Read};
+
+    let mut header_bytes: Vec<u8> = Vec::new();
+    loop {
+        let start = header_bytes.len();
+        // One byte past the cap tells an oversized header from one that fills it exactly.
+        let remaining = (MAX_STREAM_HEADER_BYTES + 1 - start) as u64;
+        let n = reader.by_ref().take(remaining).read_until(b'\n', &mut header_bytes).map_err(stream_error)?;
+        if n == 0 || header_bytes.len() > MAX_STREAM_HEADER_BYTES {
+            return Err(PlyError::msg(PlyErrorKind::Header, "PLY: can't find end_header"));
+        }
+        if header_bytes[start..].trim_ascii() == b"end_header" {
+            return Ok(header_bytes);
+        }
+    }
+}
+
+fn stream_error(e: std::io::Error) -> PlyError {
+    if e.kind() == std::io::ErrorKind::UnexpectedEof {
+        PlyError::msg(PlyErrorKind::Truncated, "PLY: out of bounds while reading binary data")
+    } else {
+        PlyError::MsgOwned(PlyErrorKind::Io, format!("PLY: io error: {e}"))
+    }
+}
+
+/// Like [`parse_splat_ply_core_with_options`], but pulls the file from `reader` instead of a
+/// slice, so the whole file never has to be in memory: beyond the output buffers it holds the
+/// header, one binary record (or ASCII line) at a time, and a small read buffer. Elements
+/// before `vertex` are skipped as they stream by; anything after it is never read.
+///
+/// The data is read once, so what needs a second look at it is unavailable:
+/// - 0–255 opacity detection: with `opacity_mode: None` opacities follow the profile or
+///   `assume_logit_opacity`; set `opacity_mode` for 0–255 files;
+/// - [`ScaleMode::Auto`]: scales follow the profile or `assume_log_scale` instead;
+/// - `lenient_format`: a mislabeled `ascii` header fails to parse instead;
+/// - [`VertexLayout::Columnar`]: rejected, since its records aren't contiguous.
+pub fn parse_splat_ply_reader<R: std::io::Read>(
+    reader: R,
+    opts: &ParseOptionsCore,
+) -> Result<SplatPlyBuffersCore, PlyError> {
+    use std::io::{BufRead, Read};
+
+    let mut reader = std::io::BufReader::new(reader);
+    let header_bytes = read_header_bytes(&mut reader)?;
+    let header = parse_header(&header_bytes)?;
+    let el = find_splat_element(&header, opts)?;
+    let format = header.format;
+    if binary_stride(el).is_none() {
+        return Err(PlyError::msg(
+            PlyErrorKind::Unsupported,
+            "PLY: vertex list properties can't be streamed; parse it from a byte slice instead",
+        ));
+    }
+    if format != PlyFormat::Ascii
+        && opts.vertex_layout.or(header.vertex_layout_hint()) == Some(VertexLayout::Columnar)
+    {
+        return Err(PlyError::msg(
+            PlyErrorKind::Unsupported,
+            "PLY: columnar vertex data can't be streamed; parse it from a byte slice instead",
+        ));
+    }
+
+    let fields = resolve_fields(el, &opts.exporter_profile.names(), opts.quat_layout_override)?;
+    let params = decode_params(opts, &header, format, || Ok(false), || Ok(opts.encoding().0))?;
+    // The header count is untrusted until the records arrive; the buffers grow past the reserve.
+    let mut acc = SplatAccumulator::with_capacity(el.count.min(STREAM_RESERVE_SPLATS), fields.sh_degree, opts);
+
+    match format {
+        PlyFormat::BinaryLittleEndian | PlyFormat::BinaryBigEndian => {
+            let little = format == PlyFormat::BinaryLittleEndian;
+            for other in elements_before(&header, el) {
+                skip_binary_element(&mut reader, other, little)?;
+            }
+            let (offsets, stride) = scalar_offsets(el);
+            let mut record = vec![0u8; stride];
+            for _ in 0..el.count {
+                reader.read_exact(&mut record).map_err(stream_error)?;
+                let row = BinaryRow {
+                    bytes: &record,
+                    base: 0,
+                    offsets: &offsets,
+                    little,
+                };
+                if acc.push(decode_vertex(&row, &fields, &params)?)? {
+                    acc.push_sh(&row, &fields)?;
+                }
+            }
+        }
+        PlyFormat::Ascii => {
+            let mut skip = ascii_lines_before(&header, el)?;
+            let mut remaining = el.count;
+            let mut line = String::new();
+            while remaining > 0 {
+                line.clear();
+                if reader.read_line(&mut line).map_err(stream_error)? == 0 {
+                    return Err(ascii_lines_missing());
+                }
+                if line.trim().is_empty() {
+                    continue;
+                }
+                if skip > 0 {
+                    skip -= 1;
+                    continue;
+                }
+                let row = AsciiRow {
+                    parts: line.split_whitespace().collect(),
+                };
+                if acc.push(decode_vertex(&row, &fields, &params)?)? {
+                    acc.push_sh(&row, &fields)?;
+                }
+                remaining -= 1;
+            }
+        }
+    }
+    let out = acc.finish(&header, format, el, params.scale_mode(&fields));
+    Ok(postprocess(out, opts, &mut Trace::new(None)))
+}
+
+/// Reads past the binary data of `el`, walking list properties record by record.
+fn skip_binary_element<R: std::io::Read>(reader: &mut R, el: &PlyElement, little: bool) -> Result<(), PlyError> {
+    fn discard<R: std::io::Read>(reader: &mut R, len: usize) -> Result<(), PlyError> {
+        let mut limited = std::io::Read::take(reader, len as u64);
+        let copied = std::io::copy(&mut limited, &mut std::io::sink()).map_err(stream_error)?;
+        if copied < len as u64 {
+            return Err(PlyError::msg(PlyErrorKind::Truncated, "PLY: out of bounds while reading binary data"));
+        }
+        Ok(())
+    }
+    let overflow = || PlyError::msg(PlyErrorKind::InvalidData, "PLY: element size overflows");
+    if let Some(stride) = binary_stride(el) {
+        let len = el.count.checked_mul(stride).ok_or_else(overflow)?;
+        return discard(reader, len);
+    }
+    for _ in 0..el.count {
+        for p in &el.properties {
+            match p {
+                PlyProperty::Scalar { ty, .. } => discard(reader, ty.size_bytes())?,
+                PlyProperty::List {
+                    count_ty, item_ty, ..
+                } => {
+                    let mut buf = [0u8; 8];
+                    let count_bytes = &mut buf[..count_ty.size_bytes()];
+                    reader.read_exact(count_bytes).map_err(stream_error)?;
+                    let n = read_scalar(count_bytes, 0, *count_ty, little)?;
+                    if !(n >= 0.0 && n.fract() == 0.0) {
+                        return Err(PlyError::MsgOwned(
+                            PlyErrorKind::InvalidData,
+                            format!("PLY: list length {n} is not a non-negative integer"),
+                        ));
+                    }
+                    discard(reader, (n as usize).checked_mul(item_ty.size_bytes()).ok_or_else(overflow)?)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Bounding box `(min, max)` of the vertex positions, reading nothing but the position
+/// properties and allocating no splat buffers; an empty file gets `min = +inf`, `max = -inf`.
+///
+/// Of `opts` it honors only what locates and scales the positions: `element_name`,
+/// `exporter_profile`, `vertex_layout`, `lenient_format` and `unit_hint`, plus `nan_policy`
+/// applied to the positions alone. Everything that drops or moves splats after decoding is
+/// ignored, so the bbox differs from a full parse's with `min_alpha`, `max_output_splats`,
+/// `nan_policy: Skip` on a non-finite non-position field, or a model transform
+/// ([`parse_splat_ply_core_with_transform`]). SuperSplat compressed files are rejected.
+pub fn parse_bbox_only(bytes: &[u8], opts: &ParseOptionsCore) -> Result<([f32; 3], [f32; 3]), PlyError> {
+    let (header, format) = open_vertex_data(bytes, opts, &mut Trace::new(None))?;
+    if is_supersplat_compressed(&header) {
+        return Err(PlyError::msg(
+            PlyErrorKind::Unsupported,
+            "PLY: SuperSplat compressed positions need a full parse for their bbox",
+        ));
+    }
+    let el = find_splat_element(&header, opts)?;
+    let position = resolve_position(&property_map(el), &opts.exporter_profile.names())?;
+    position_bbox(bytes, &header, el, format, opts, position)
+}
+
+/// Position-only bbox pass shared by [`parse_bbox_only`] and [`probe_splat_ply_core`].
+fn position_bbox(
+    bytes: &[u8],
+    header: &PlyHeaderInfo,
+    el: &PlyElement,
+    format: PlyFormat,
+    opts: &ParseOptionsCore,
+    position: [Field; 3],
+) -> Result<([f32; 3], [f32; 3]), PlyError> {
+    let mut bbox_min = [f32::INFINITY; 3];
+    let mut bbox_max = [f32::NEG_INFINITY; 3];
+    let layout = opts.vertex_layout.or(header.vertex_layout_hint()).unwrap_or_default();
+    let mut index = 0usize;
+    for_each_vertex(bytes, header, el, format, layout, |row| {
+        index += 1;
+        let mut p = [0.0f32; 3];
+        for (v, field) in p.iter_mut().zip(position) {
+            *v = row.get(field)? as f32;
+        }
+        if let (Some(policy), false) = (opts.nan_policy, p.iter().all(|v| v.is_finite())) {
+            match policy {
+                NanPolicy::Error => return Err(non_finite_vertex(index - 1)),
+                NanPolicy::Skip => return Ok(()),
+                NanPolicy::Zero => p = p.map(|v| if v.is_finite() { v } else { 0.0 }),
+            }
+        }
+        for k in 0..3 {
+            bbox_min[k] = bbox_min[k].min(p[k]);
+            bbox_max[k] = bbox_max[k].max(p[k]);
+        }
+        Ok(())
+    })?;
+    let factor = opts.unit_hint.factor();
+    Ok((bbox_min.map(|v| v * factor), bbox_max.map(|v| v * factor)))
+}
+
+/// Cheap summary of a splat PLY from [`probe_splat_ply_core`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplatProbeCore {
+    /// Number of vertices in the file.
+    pub count: u32,
+    /// Format the data is decoded as (after `lenient_format`).
+    pub format: PlyFormat,
+    /// SH degree implied by the `f_rest_*` properties.
+    pub sh_degree: u32,
+    pub bbox_min: [f32; 3],
+    pub bbox_max: [f32; 3],
+    /// Buffers a full parse with the same options would populate.
+    pub fields: FieldFlags,
+}
+
+/// Validates that `bytes` is a splat PLY a full parse would accept at the header and field
+/// level, and returns its count, format, SH degree and bbox without building splat buffers.
+/// Only the position properties are read, as in [`parse_bbox_only`]; `count` and the bbox
+/// cover every vertex (`max_output_splats` is ignored), and `unit_hint` is applied.
+pub fn probe_splat_ply_core(bytes: &[u8], opts: &ParseOptionsCore) -> Result<SplatProbeCore, PlyError> {
+    let (header, format) = open_vertex_data(bytes, opts, &mut Trace::new(None))?;
+    let el = find_splat_element(&header, opts)?;
+    let fields = resolve_fields(el, &opts.exporter_profile.names(), opts.quat_layout_override)?;
+    let (bbox_min, bbox_max) = position_bbox(bytes, &header, el, format, opts, fields.position)?;
+    Ok(SplatProbeCore {
+        count: el.count as u32,
+        format,
+        sh_degree: fields.sh_degree,
+        bbox_min,
+        bbox_max,
+        fields: output_fields(el.count, opts, &fields),
+    })
+}
+
+/// Buffers a parse of `count` vertices with `opts` and `fields` populates.
+fn output_fields(count: usize, opts: &ParseOptionsCore, fields: &SplatFields) -> FieldFlags {
+    let mut flags = FieldFlags::default();
+    if count > 0 {
+        flags = flags.with(FieldFlags::CENTER).with(FieldFlags::COVARIANCE).with(FieldFlags::RGBA);
+        if opts.center_f64 {
+            flags = flags.with(FieldFlags::CENTER_F64);
+        }
+        if fields.sh_degree > 0 {
+            flags = flags.with(FieldFlags::SH);
+        }
+        if opts.keep_raw && matches!(fields.geometry, GeometryFields::ScaleRotation { .. }) {
+            flags = flags.with(FieldFlags::SCALE_QUAT);
+        }
+    }
+    flags
+}
+
+/// Shape of the buffers a parse would produce, from [`output_layout`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutputLayout {
+    /// Splats in the output: the vertex count, capped by `max_output_splats`. With `min_alpha`
+    /// this is an upper bound, since the skipped splats aren't known before decoding.
+    pub count: usize,
+    /// Buffers that will be populated, as [`SplatPlyBuffersCore::present_fields`] reports them.
+    pub fields: FieldFlags,
+    /// SH degree of `sh`.
+    pub sh_degree: u32,
+    /// Values per splat in `center` (f32); 0 for buffers that are not produced.
+    pub center: usize,
+    /// Values per splat in `covariance` (f32).
+    pub covariance: usize,
+    /// Values per splat in `rgba` (u32).
+    pub rgba: usize,
+    /// Values per splat in `center_f64` (f64).
+    pub center_f64: usize,
+    /// Values per splat in `sh` (f32).
+    pub sh: usize,
+    /// Values per splat in `scales` (f32).
+    pub scales: usize,
+    /// Values per splat in `quats` (f32).
+    pub quats: usize,
+}
+
+impl OutputLayout {
+    /// Bytes per splat across all buffers.
+    pub fn bytes_per_splat(&self) -> usize {
+        4 * (self.center + self.covariance + self.rgba + self.sh + self.scales + self.quats) + 8 * self.center_f64
+    }
+
+    /// Bytes of all buffers together, or `None` if that doesn't fit in `usize` (a header
+    /// count no real file could back).
+    pub fn total_bytes(&self) -> Option<usize> {
+        self.count.checked_mul(self.bytes_per_splat())
+    }
+}
+
+/// Predicts the buffers [`parse_splat_ply_core_with_options`] would produce for a file with
+/// `header`, without reading any data, e.g. to preallocate. Fails like a parse would if the
+/// vertex fields can't be resolved.
+pub fn output_layout(opts: &ParseOptionsCore, header: &PlyHeaderInfo) -> Result<OutputLayout, PlyError> {
+    let el = find_splat_element(header, opts)?;
+    reject_vertex_lists(el, header.format)?;
+    let fields = resolve_fields(el, &opts.exporter_profile.names(), opts.quat_layout_override)?;
+    let count = opts.max_output_splats.map_or(el.count, |max| el.count.min(max));
+    let flags = output_fields(count, opts, &fields);
+    let per_splat = |bit: u32, n: usize| if flags.has(bit) { n } else { 0 };
+    Ok(OutputLayout {
+        count,
+        fields: flags,
+        sh_degree: fields.sh_degree,
+        center: per_splat(FieldFlags::CENTER, 3),
+        covariance: per_splat(FieldFlags::COVARIANCE, 6),
+        rgba: per_splat(FieldFlags::RGBA, 1),
+        center_f64: per_splat(FieldFlags::CENTER_F64, 3),
+        sh: per_splat(FieldFlags::SH, sh_rest_len(fields.sh_degree)),
+        scales: per_splat(FieldFlags::SCALE_QUAT, 3),
+        quats: per_splat(FieldFlags::SCALE_QUAT, 4),
     })
 }
 
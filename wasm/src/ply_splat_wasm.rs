@@ -1,10 +1,20 @@
 use wasm_bindgen::prelude::*;
 
-use crate::ply_splat_core::{parse_splat_ply_core, parse_splat_ply_core_with_opts, SplatPlyBuffersCore};
+use crate::ply_splat_core::{
+    find_vertex_element, parse_ply_header, parse_splat_ply_core, parse_splat_ply_core_debug, parse_splat_ply_core_downsampled, parse_splat_ply_core_gz, parse_splat_ply_core_subsampled, parse_splat_ply_core_traced, parse_splat_ply_core_verbose,
+    parse_splat_ply_core_with_options, parse_splat_ply_core_with_opts, parse_splat_ply_core_with_progress,
+    parse_splat_ply_core_with_transform, probe_splat_ply_core, ParseOptionsCore,
+    OpacityMode, PlyError, PlyHeaderInfo, PlyProperty, QuatLayout, ReorderMode, ScaleMode,
+    SplatPlyBuffersCore, SplatProbeCore,
+};
 
+/// Decoded splats. The typed-array getters (`center`, `covariance`, `rgba`, ...) are zero-copy
+/// views into wasm memory: any later allocation that grows the memory, such as parsing another
+/// file, detaches them and they read as empty. Copy what you keep (`center.slice()`), or use
+/// `parse_splat_ply_copied`.
 #[wasm_bindgen]
 pub struct SplatPlyBuffers {
-    inner: SplatPlyBuffersCore,
+    pub(crate) inner: SplatPlyBuffersCore,
 }
 
 #[wasm_bindgen]
@@ -43,11 +53,153 @@ impl SplatPlyBuffers {
     pub fn bbox_max(&self) -> js_sys::Float32Array {
         unsafe { js_sys::Float32Array::view(&self.inner.bbox_max) }
     }
+
+    /// Center of a bounding sphere of the splat centers (the bbox center).
+    #[wasm_bindgen(getter, js_name = bsphereCenter)]
+    pub fn bsphere_center(&self) -> js_sys::Float32Array {
+        unsafe { js_sys::Float32Array::view(&self.inner.bsphere_center) }
+    }
+
+    /// Radius of the bounding sphere around `bsphereCenter`; 0 for an empty scene.
+    #[wasm_bindgen(getter, js_name = bsphereRadius)]
+    pub fn bsphere_radius(&self) -> f32 {
+        self.inner.bsphere_radius
+    }
+
+    /// The file's header marks it antialiased (`comment antialiased true`): its low-pass filter
+    /// is baked in, so skip the renderer's own blur compensation.
+    #[wasm_bindgen(getter)]
+    pub fn antialiased(&self) -> bool {
+        self.inner.antialiased
+    }
+
+    /// How the file stored its scales: `"log"` or `"linear"` (what `scaleMode: "auto"` picked),
+    /// or `undefined` when the covariances didn't come from scale properties.
+    #[wasm_bindgen(getter, js_name = scaleMode)]
+    pub fn scale_mode(&self) -> Option<String> {
+        self.inner.scale_mode.map(|m| m.as_str().to_string())
+    }
+
+    /// Opacity-weighted mean of the splat centers (the plain mean if every alpha is 0), e.g.
+    /// as a camera target.
+    #[wasm_bindgen(getter)]
+    pub fn centroid(&self) -> js_sys::Float32Array {
+        unsafe { js_sys::Float32Array::view(&self.inner.centroid) }
+    }
+
+    /// Full-precision centers; `undefined` unless parsed with `parse_splat_ply_with_center_f64`.
+    #[wasm_bindgen(getter, js_name = centerF64)]
+    pub fn center_f64(&self) -> Option<js_sys::Float64Array> {
+        self.inner
+            .center_f64
+            .as_ref()
+            .map(|c| unsafe { js_sys::Float64Array::view(c) })
+    }
+
+    /// Higher-order SH coefficients, `3 * ((shDegree + 1)² - 1)` per splat, channel-major
+    /// (all red, then green, then blue). Empty for degree 0.
+    #[wasm_bindgen(getter)]
+    pub fn sh(&self) -> js_sys::Float32Array {
+        unsafe { js_sys::Float32Array::view(&self.inner.sh) }
+    }
+
+    /// Linear per-splat scales (3N), kept only when parsed with `keep_raw`; empty otherwise.
+    #[wasm_bindgen(getter)]
+    pub fn scales(&self) -> js_sys::Float32Array {
+        unsafe { js_sys::Float32Array::view(&self.inner.scales) }
+    }
+
+    /// Unit per-splat rotations (4N, `[x, y, z, w]`), empty together with `scales`.
+    #[wasm_bindgen(getter)]
+    pub fn quats(&self) -> js_sys::Float32Array {
+        unsafe { js_sys::Float32Array::view(&self.inner.quats) }
+    }
+
+    #[wasm_bindgen(getter, js_name = shDegree)]
+    pub fn sh_degree(&self) -> u32 {
+        self.inner.sh_degree
+    }
+
+    /// PLY element the splats were read from, usually `vertex`.
+    #[wasm_bindgen(getter, js_name = elementName)]
+    pub fn element_name(&self) -> String {
+        self.inner.element_name.clone()
+    }
+
+    /// One splat as `{ x, y, z, cov, r, g, b, a }`, with `cov` the 6 covariance values and the
+    /// color as 0–255 bytes. Throws if `index` is out of range.
+    #[wasm_bindgen(js_name = getSplat)]
+    pub fn get_splat(&self, index: u32) -> Result<JsValue, JsValue> {
+        let (center, cov, rgba) = self.inner.splat(index as usize).ok_or_else(|| {
+            JsValue::from_str(&format!("splat index {index} out of range (count {})", self.inner.count))
+        })?;
+        let out = js_sys::Object::new();
+        let set = |key: &str, value: &JsValue| js_sys::Reflect::set(&out, &JsValue::from_str(key), value);
+        for (key, v) in ["x", "y", "z"].into_iter().zip(center) {
+            set(key, &JsValue::from(v))?;
+        }
+        set("cov", &cov.iter().map(|&v| JsValue::from(v)).collect::<js_sys::Array>())?;
+        for (k, key) in ["r", "g", "b", "a"].into_iter().enumerate() {
+            set(key, &JsValue::from((rgba >> (8 * k)) & 0xff))?;
+        }
+        Ok(out.into())
+    }
+
+    /// Center, covariance and rgba of every splat interleaved in one `Uint8Array` (40 bytes
+    /// per splat: 3 × f32 center at 0, 6 × f32 covariance at 12, u32 rgba at 36), for a
+    /// single `bufferData`. A JS-owned copy.
+    pub fn interleaved(&self) -> js_sys::Uint8Array {
+        js_sys::Uint8Array::from(&self.inner.interleaved()[..])
+    }
+
+    /// Bitmask of populated buffers: bit 0 `center`, bit 1 `covariance`, bit 2 `rgba`,
+    /// bit 3 `centerF64`, bit 4 `sh`, bit 5 `scales` and `quats`.
+    #[wasm_bindgen(getter, js_name = presentFields)]
+    pub fn present_fields(&self) -> u32 {
+        self.inner.present_fields().0
+    }
+}
+
+/// Converts a `PlyError` into a JS `Error` carrying a `kind` string next to its `message`, so
+/// callers can branch on `err.kind` (`"missing_property"`, `"truncated"`, ...).
+pub(crate) fn ply_error_to_js(e: PlyError) -> JsValue {
+    let err = js_sys::Error::new(&e.to_string());
+    let _ = js_sys::Reflect::set(&err, &JsValue::from_str("kind"), &JsValue::from_str(e.kind().as_str()));
+    err.into()
 }
 
 #[wasm_bindgen]
 pub fn parse_splat_ply(bytes: &[u8]) -> Result<SplatPlyBuffers, JsValue> {
-    let inner = parse_splat_ply_core(bytes).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let inner = parse_splat_ply_core(bytes).map_err(ply_error_to_js)?;
+    Ok(SplatPlyBuffers { inner })
+}
+
+/// Like `parse_splat_ply`, but takes the file as an `ArrayBuffer` and returns a plain object
+/// `{ count, format, shDegree, center, covariance, rgba, sh, bboxMin, bboxMax }` whose typed
+/// arrays are JS-owned copies, so they stay valid when wasm memory grows.
+#[wasm_bindgen]
+pub fn parse_splat_ply_copied(data: &js_sys::ArrayBuffer) -> Result<js_sys::Object, JsValue> {
+    let bytes = js_sys::Uint8Array::new(data).to_vec();
+    let inner = parse_splat_ply_core(&bytes).map_err(ply_error_to_js)?;
+    let out = js_sys::Object::new();
+    let set = |key: &str, value: &JsValue| js_sys::Reflect::set(&out, &JsValue::from_str(key), value);
+    set("count", &JsValue::from(inner.count))?;
+    set("format", &JsValue::from_str(inner.format.as_str()))?;
+    set("shDegree", &JsValue::from(inner.sh_degree))?;
+    set("center", &js_sys::Float32Array::from(&inner.center[..]))?;
+    set("covariance", &js_sys::Float32Array::from(&inner.covariance[..]))?;
+    set("rgba", &js_sys::Uint32Array::from(&inner.rgba[..]))?;
+    set("sh", &js_sys::Float32Array::from(&inner.sh[..]))?;
+    set("bboxMin", &js_sys::Float32Array::from(&inner.bbox_min[..]))?;
+    set("bboxMax", &js_sys::Float32Array::from(&inner.bbox_max[..]))?;
+    Ok(out)
+}
+
+/// Like `parse_splat_ply`, but also accepts gzip-compressed input (`.ply.gz`) of up to 2 GiB
+/// decompressed.
+#[wasm_bindgen]
+pub fn parse_splat_ply_gz(bytes: &[u8]) -> Result<SplatPlyBuffers, JsValue> {
+    let inner = parse_splat_ply_core_gz(bytes).map_err(ply_error_to_js)?;
     Ok(SplatPlyBuffers { inner })
 }
 
@@ -58,8 +210,365 @@ pub fn parse_splat_ply_with_opts(
     assume_logit_opacity: bool,
 ) -> Result<SplatPlyBuffers, JsValue> {
     let inner = parse_splat_ply_core_with_opts(bytes, assume_log_scale, assume_logit_opacity)
-        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        .map_err(ply_error_to_js)?;
     Ok(SplatPlyBuffers { inner })
 }
 
+/// Decode options for `parse_splat_ply_with_options`. A new `ParseOptions` decodes like
+/// `parse_splat_ply`; modes left `undefined` are detected or follow the defaults.
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct ParseOptions {
+    inner: ParseOptionsCore,
+}
 
+#[wasm_bindgen]
+impl ParseOptions {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> ParseOptions {
+        ParseOptions::default()
+    }
+
+    /// `"log"`, `"linear"` or `"auto"` (decide from the data); `undefined` reads log scales.
+    #[wasm_bindgen(getter, js_name = scaleMode)]
+    pub fn scale_mode(&self) -> Option<String> {
+        self.inner.scale_mode.map(|m| m.as_str().to_string())
+    }
+
+    #[wasm_bindgen(setter, js_name = scaleMode)]
+    pub fn set_scale_mode(&mut self, mode: Option<String>) -> Result<(), JsValue> {
+        self.inner.scale_mode = parse_mode(mode, ScaleMode::parse, "scaleMode: expected log, linear or auto")?;
+        Ok(())
+    }
+
+    /// `"logit"`, `"linear"` or `"scaled255"`; `undefined` detects 0–255 values, else logit.
+    #[wasm_bindgen(getter, js_name = opacityMode)]
+    pub fn opacity_mode(&self) -> Option<String> {
+        self.inner.opacity_mode.map(|m| m.as_str().to_string())
+    }
+
+    #[wasm_bindgen(setter, js_name = opacityMode)]
+    pub fn set_opacity_mode(&mut self, mode: Option<String>) -> Result<(), JsValue> {
+        self.inner.opacity_mode =
+            parse_mode(mode, OpacityMode::parse, "opacityMode: expected logit, linear or scaled255")?;
+        Ok(())
+    }
+
+    /// Drop splats whose alpha byte is below this; `undefined` keeps all.
+    #[wasm_bindgen(getter, js_name = minAlpha)]
+    pub fn min_alpha(&self) -> Option<u8> {
+        self.inner.min_alpha
+    }
+
+    #[wasm_bindgen(setter, js_name = minAlpha)]
+    pub fn set_min_alpha(&mut self, min_alpha: Option<u8>) {
+        self.inner.min_alpha = min_alpha;
+    }
+
+    /// `"wxyz"`, `"xyzw"` or `"xyz"` to force how quaternions are read; `undefined` detects it
+    /// from the property names.
+    #[wasm_bindgen(getter, js_name = quatLayout)]
+    pub fn quat_layout(&self) -> Option<String> {
+        self.inner.quat_layout_override.map(|l| l.as_str().to_string())
+    }
+
+    #[wasm_bindgen(setter, js_name = quatLayout)]
+    pub fn set_quat_layout(&mut self, layout: Option<String>) -> Result<(), JsValue> {
+        self.inner.quat_layout_override =
+            parse_mode(layout, QuatLayout::parse, "quatLayout: expected wxyz, xyzw or xyz")?;
+        Ok(())
+    }
+
+    /// Also fill `scales` and `quats`.
+    #[wasm_bindgen(getter, js_name = keepRaw)]
+    pub fn keep_raw(&self) -> bool {
+        self.inner.keep_raw
+    }
+
+    #[wasm_bindgen(setter, js_name = keepRaw)]
+    pub fn set_keep_raw(&mut self, keep_raw: bool) {
+        self.inner.keep_raw = keep_raw;
+    }
+}
+
+/// `None` for `undefined`, else `parse(value)` or an error `"{expected}, got {value:?}"`.
+fn parse_mode<T>(value: Option<String>, parse: fn(&str) -> Option<T>, expected: &str) -> Result<Option<T>, JsValue> {
+    value
+        .map(|s| parse(&s).ok_or_else(|| JsValue::from_str(&format!("{expected}, got {s:?}"))))
+        .transpose()
+}
+
+/// Parses with the options set on `opts`.
+#[wasm_bindgen]
+pub fn parse_splat_ply_with_options(bytes: &[u8], opts: &ParseOptions) -> Result<SplatPlyBuffers, JsValue> {
+    let inner = parse_splat_ply_core_with_options(bytes, &opts.inner).map_err(ply_error_to_js)?;
+    Ok(SplatPlyBuffers { inner })
+}
+
+/// Parses with default options, keeping at most `max_splats` of the most important splats.
+#[wasm_bindgen]
+pub fn parse_splat_ply_with_max_splats(bytes: &[u8], max_splats: u32) -> Result<SplatPlyBuffers, JsValue> {
+    let opts = ParseOptionsCore {
+        max_output_splats: Some(max_splats as usize),
+        ..ParseOptionsCore::default()
+    };
+    let inner = parse_splat_ply_core_with_options(bytes, &opts).map_err(ply_error_to_js)?;
+    Ok(SplatPlyBuffers { inner })
+}
+
+/// Parses with default options, then applies the column-major 4x4 model matrix `transform`
+/// to centers and covariances; the bbox is in transformed space.
+#[wasm_bindgen]
+pub fn parse_splat_ply_with_transform(bytes: &[u8], transform: &[f32]) -> Result<SplatPlyBuffers, JsValue> {
+    let transform: &[f32; 16] = transform.try_into().map_err(|_| {
+        JsValue::from_str(&format!("transform: expected 16 floats, got {}", transform.len()))
+    })?;
+    let inner = parse_splat_ply_core_with_transform(bytes, &ParseOptionsCore::default(), transform)
+        .map_err(ply_error_to_js)?;
+    Ok(SplatPlyBuffers { inner })
+}
+
+/// Parses with default options and also fills `centerF64`.
+#[wasm_bindgen]
+pub fn parse_splat_ply_with_center_f64(bytes: &[u8]) -> Result<SplatPlyBuffers, JsValue> {
+    let opts = ParseOptionsCore {
+        center_f64: true,
+        ..ParseOptionsCore::default()
+    };
+    let inner = parse_splat_ply_core_with_options(bytes, &opts).map_err(ply_error_to_js)?;
+    Ok(SplatPlyBuffers { inner })
+}
+
+/// Parses with default options, merging splats into one per occupied `voxel_size` cube for
+/// preview rendering.
+#[wasm_bindgen]
+pub fn parse_splat_ply_downsampled(bytes: &[u8], voxel_size: f32) -> Result<SplatPlyBuffers, JsValue> {
+    let inner = parse_splat_ply_core_downsampled(bytes, &ParseOptionsCore::default(), voxel_size)
+        .map_err(ply_error_to_js)?;
+    Ok(SplatPlyBuffers { inner })
+}
+
+/// Parses with default options, keeping a random `fraction` of the splats; the same `seed`
+/// keeps the same splats every time.
+#[wasm_bindgen]
+pub fn parse_splat_ply_subsampled(bytes: &[u8], fraction: f32, seed: u64) -> Result<SplatPlyBuffers, JsValue> {
+    let inner = parse_splat_ply_core_subsampled(bytes, &ParseOptionsCore::default(), fraction, seed)
+        .map_err(ply_error_to_js)?;
+    Ok(SplatPlyBuffers { inner })
+}
+
+/// Parses with default options, reading the quaternion properties as `quatLayout`: `"wxyz"`,
+/// `"xyzw"`, `"xyz"` (w rebuilt), or `"auto"` to detect it from their names.
+#[wasm_bindgen]
+pub fn parse_splat_ply_with_quat_layout(bytes: &[u8], quat_layout: &str) -> Result<SplatPlyBuffers, JsValue> {
+    let quat_layout_override = match quat_layout {
+        "auto" => None,
+        s => Some(QuatLayout::parse(s).ok_or_else(|| {
+            JsValue::from_str(&format!("quatLayout: expected wxyz, xyzw, xyz or auto, got {s:?}"))
+        })?),
+    };
+    let opts = ParseOptionsCore {
+        quat_layout_override,
+        ..ParseOptionsCore::default()
+    };
+    let inner = parse_splat_ply_core_with_options(bytes, &opts).map_err(ply_error_to_js)?;
+    Ok(SplatPlyBuffers { inner })
+}
+
+/// Parses with default options, ordering the splats by the Morton code of their centers.
+#[wasm_bindgen]
+pub fn parse_splat_ply_with_morton_order(bytes: &[u8]) -> Result<SplatPlyBuffers, JsValue> {
+    let opts = ParseOptionsCore {
+        reorder: ReorderMode::Morton,
+        ..ParseOptionsCore::default()
+    };
+    let inner = parse_splat_ply_core_with_options(bytes, &opts).map_err(ply_error_to_js)?;
+    Ok(SplatPlyBuffers { inner })
+}
+
+/// Parses with default options and also fills `scales` and `quats`.
+#[wasm_bindgen]
+pub fn parse_splat_ply_with_raw(bytes: &[u8]) -> Result<SplatPlyBuffers, JsValue> {
+    let opts = ParseOptionsCore {
+        keep_raw: true,
+        ..ParseOptionsCore::default()
+    };
+    let inner = parse_splat_ply_core_with_options(bytes, &opts).map_err(ply_error_to_js)?;
+    Ok(SplatPlyBuffers { inner })
+}
+
+/// Parses with default options, calling `on_event(message)` at each parse milestone.
+#[wasm_bindgen]
+pub fn parse_splat_ply_traced(bytes: &[u8], on_event: &js_sys::Function) -> Result<SplatPlyBuffers, JsValue> {
+    let mut emit = |msg: &str| {
+        let _ = on_event.call1(&JsValue::NULL, &JsValue::from_str(msg));
+    };
+    let inner = parse_splat_ply_core_traced(bytes, &ParseOptionsCore::default(), Some(&mut emit))
+        .map_err(ply_error_to_js)?;
+    Ok(SplatPlyBuffers { inner })
+}
+
+/// Parses with `opts`, calling `on_progress(fraction)` with the share of vertices decoded so
+/// far, in `[0, 1]`, about every 1% of the vertices. Exceptions thrown by `on_progress` are
+/// ignored.
+#[wasm_bindgen]
+pub fn parse_splat_ply_with_progress(
+    bytes: &[u8],
+    opts: &ParseOptions,
+    on_progress: &js_sys::Function,
+) -> Result<SplatPlyBuffers, JsValue> {
+    let mut report = |fraction: f32| {
+        let _ = on_progress.call1(&JsValue::NULL, &JsValue::from(fraction));
+    };
+    let inner = parse_splat_ply_core_with_progress(bytes, &opts.inner, &mut report)
+        .map_err(ply_error_to_js)?;
+    Ok(SplatPlyBuffers { inner })
+}
+
+/// Parses with default options and returns `{ buffers, decisions, warnings }`: `decisions`
+/// lists the encodings chosen (scales, opacity, colors), `warnings` any likely misdetections
+/// and is empty for a clean file. Both are arrays of strings.
+#[wasm_bindgen]
+pub fn parse_splat_ply_verbose(bytes: &[u8]) -> Result<js_sys::Object, JsValue> {
+    let (inner, diagnostics) =
+        parse_splat_ply_core_verbose(bytes, &ParseOptionsCore::default()).map_err(ply_error_to_js)?;
+    let strings = |items: &[String]| items.iter().map(|s| JsValue::from_str(s)).collect::<js_sys::Array>();
+    let out = js_sys::Object::new();
+    js_sys::Reflect::set(&out, &JsValue::from_str("buffers"), &SplatPlyBuffers { inner }.into())?;
+    js_sys::Reflect::set(&out, &JsValue::from_str("decisions"), &strings(&diagnostics.decisions))?;
+    js_sys::Reflect::set(&out, &JsValue::from_str("warnings"), &strings(&diagnostics.warnings))?;
+    Ok(out)
+}
+
+/// Parses with default options and returns `{ buffers, dump }`, where `dump` is a plain-text
+/// summary for bug reports: format, field mapping, quaternion layout and the first and last
+/// splat.
+#[wasm_bindgen]
+pub fn parse_splat_ply_debug(bytes: &[u8]) -> Result<js_sys::Object, JsValue> {
+    let (inner, dump) =
+        parse_splat_ply_core_debug(bytes, &ParseOptionsCore::default()).map_err(ply_error_to_js)?;
+    let out = js_sys::Object::new();
+    js_sys::Reflect::set(&out, &JsValue::from_str("buffers"), &SplatPlyBuffers { inner }.into())?;
+    js_sys::Reflect::set(&out, &JsValue::from_str("dump"), &JsValue::from_str(&dump))?;
+    Ok(out)
+}
+
+/// Count, format, SH degree and bbox of a splat PLY, without decoding the splats.
+#[wasm_bindgen]
+pub struct SplatProbe {
+    inner: SplatProbeCore,
+}
+
+#[wasm_bindgen]
+impl SplatProbe {
+    #[wasm_bindgen(getter)]
+    pub fn count(&self) -> u32 {
+        self.inner.count
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn format(&self) -> String {
+        self.inner.format.as_str().to_string()
+    }
+
+    #[wasm_bindgen(getter, js_name = shDegree)]
+    pub fn sh_degree(&self) -> u32 {
+        self.inner.sh_degree
+    }
+
+    #[wasm_bindgen(getter, js_name = bboxMin)]
+    pub fn bbox_min(&self) -> js_sys::Float32Array {
+        js_sys::Float32Array::from(&self.inner.bbox_min[..])
+    }
+
+    #[wasm_bindgen(getter, js_name = bboxMax)]
+    pub fn bbox_max(&self) -> js_sys::Float32Array {
+        js_sys::Float32Array::from(&self.inner.bbox_max[..])
+    }
+
+    /// Same bitmask as `SplatPlyBuffers.presentFields` for a full parse of the file.
+    #[wasm_bindgen(getter)]
+    pub fn fields(&self) -> u32 {
+        self.inner.fields.0
+    }
+}
+
+/// Validates a splat PLY with default options and summarizes it without decoding the splats.
+#[wasm_bindgen]
+pub fn probe_splat_ply(bytes: &[u8]) -> Result<SplatProbe, JsValue> {
+    let inner =
+        probe_splat_ply_core(bytes, &ParseOptionsCore::default()).map_err(ply_error_to_js)?;
+    Ok(SplatProbe { inner })
+}
+
+/// Header-level metadata of a PLY file: format, elements and vertex properties.
+#[wasm_bindgen]
+pub struct PlyMetadata {
+    inner: PlyHeaderInfo,
+}
+
+#[wasm_bindgen]
+impl PlyMetadata {
+    #[wasm_bindgen(getter)]
+    pub fn format(&self) -> String {
+        self.inner.format.as_str().to_string()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn version(&self) -> String {
+        self.inner.version.clone()
+    }
+
+    #[wasm_bindgen(getter, js_name = elementNames)]
+    pub fn element_names(&self) -> js_sys::Array {
+        self.inner.elements.iter().map(|e| JsValue::from_str(&e.name)).collect()
+    }
+
+    /// Record count of each element, in the order of `elementNames`.
+    #[wasm_bindgen(getter, js_name = elementCounts)]
+    pub fn element_counts(&self) -> js_sys::Uint32Array {
+        let counts: Vec<u32> = self.inner.elements.iter().map(|e| e.count as u32).collect();
+        js_sys::Uint32Array::from(&counts[..])
+    }
+
+    /// Number of vertices (splats); 0 if there is no `vertex` element.
+    #[wasm_bindgen(getter, js_name = vertexCount)]
+    pub fn vertex_count(&self) -> u32 {
+        find_vertex_element(&self.inner).map_or(0, |e| e.count as u32)
+    }
+
+    /// Names of the vertex properties in file order; empty if there is no `vertex` element.
+    #[wasm_bindgen(getter, js_name = vertexPropertyNames)]
+    pub fn vertex_property_names(&self) -> js_sys::Array {
+        find_vertex_element(&self.inner)
+            .map(|e| e.properties.iter().map(|p| JsValue::from_str(p.name())).collect())
+            .unwrap_or_default()
+    }
+
+    /// Header types of the vertex properties, in the order of `vertexPropertyNames`, e.g.
+    /// `"float"`, or `"list uchar int"` for a list.
+    #[wasm_bindgen(getter, js_name = vertexPropertyTypes)]
+    pub fn vertex_property_types(&self) -> js_sys::Array {
+        let ty = |p: &PlyProperty| match p {
+            PlyProperty::Scalar { ty, .. } => ty.as_str().to_string(),
+            PlyProperty::List { count_ty, item_ty, .. } => {
+                format!("list {} {}", count_ty.as_str(), item_ty.as_str())
+            }
+        };
+        find_vertex_element(&self.inner)
+            .map(|e| e.properties.iter().map(|p| JsValue::from_str(&ty(p))).collect())
+            .unwrap_or_default()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn comments(&self) -> js_sys::Array {
+        self.inner.comments.iter().map(|c| JsValue::from_str(c)).collect()
+    }
+}
+
+/// Parses only the header, for listing files without decoding any vertex data.
+#[wasm_bindgen]
+pub fn parse_ply_metadata(bytes: &[u8]) -> Result<PlyMetadata, JsValue> {
+    let inner = parse_ply_header(bytes).map_err(ply_error_to_js)?;
+    Ok(PlyMetadata { inner })
+}
@@ -0,0 +1,1009 @@
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use crate::bitops_core::{
+    f32_to_f16_bits, is_bit_set_u32, set_bit_u32, zigzag_decode_u32, zigzag_encode_i32,
+};
+use crate::ply_splat_core::{
+    binary_stride, covariance_from_quat_scale, parse_header, parse_splat_ply_core_with_options,
+    read_header_bytes, rgba_to_u32, sh_rest_len, ParseOptionsCore, PlyError, PlyErrorKind,
+    PlyFormat, PlyHeaderBuilder, PlyProperty, PlyScalarType, ScaleMode, SplatBuilder,
+    SplatPlyBuffersCore, BLUE_NAMES, DC_NAMES, GREEN_NAMES, MAX_USEFUL_LOGIT, OPACITY_NAMES,
+    RED_NAMES, ROT_WXYZ_NAMES, ROT_XYZW_NAMES, SCALE_0_NAMES, SCALE_1_NAMES, SCALE_2_NAMES,
+    SH_C0, SH_REST_PREFIX, X_NAMES, Y_NAMES, Z_NAMES,
+};
+use crate::random_core::SplitMix64;
+use crate::splat_ops_core::covariance_to_scale_quat;
+
+/// Name the glTF JSON uses to reference the binary buffer returned by [`export_gltf`].
+pub const GLTF_BUFFER_URI: &str = "splats.bin";
+
+/// Vendor extension that marks the covariance accessors on the splat primitive.
+pub const GLTF_SPLAT_EXTENSION: &str = "VNY_gaussian_splats";
+
+/// Exports splats as a minimal glTF 2.0 asset: `(json, bin)`.
+///
+/// One mesh with a single `POINTS` primitive. The binary buffer holds, in order:
+/// - `POSITION`: `VEC3`/`FLOAT`, the splat centers (with bbox min/max);
+/// - `_COVARIANCE_A`: `VEC3`/`FLOAT`, covariance `(m11, m12, m13)`;
+/// - `_COVARIANCE_B`: `VEC3`/`FLOAT`, covariance `(m22, m23, m33)`;
+/// - `COLOR_0`: `VEC4`/`UNSIGNED_BYTE` normalized, the packed rgba.
+///
+/// The primitive carries a `VNY_gaussian_splats` extension pointing at the two covariance
+/// accessors, so tools that don't know about splats still see a plain colored point cloud.
+/// The JSON references the buffer as [`GLTF_BUFFER_URI`]. An empty scene exports as a glTF
+/// scene without nodes and an empty `bin`, since glTF forbids empty accessors and buffers.
+pub fn export_gltf(buffers: &SplatPlyBuffersCore) -> (Vec<u8>, Vec<u8>) {
+    let n = buffers.count as usize;
+    if n == 0 {
+        let json = concat!(
+            "{",
+            "\"asset\":{\"version\":\"2.0\",\"generator\":\"rust-wasm splat export\"},",
+            "\"scene\":0,",
+            "\"scenes\":[{}]",
+            "}"
+        );
+        return (json.as_bytes().to_vec(), Vec::new());
+    }
+
+    let mut bin: Vec<u8> = Vec::with_capacity(n * (12 + 12 + 12 + 4));
+    for v in buffers.center.iter() {
+        bin.extend_from_slice(&v.to_le_bytes());
+    }
+    let cov_a_offset = bin.len();
+    for c in buffers.covariance.chunks_exact(6) {
+        for v in &c[0..3] {
+            bin.extend_from_slice(&v.to_le_bytes());
+        }
+    }
+    let cov_b_offset = bin.len();
+    for c in buffers.covariance.chunks_exact(6) {
+        for v in &c[3..6] {
+            bin.extend_from_slice(&v.to_le_bytes());
+        }
+    }
+    let color_offset = bin.len();
+    for v in buffers.rgba.iter() {
+        bin.extend_from_slice(&v.to_le_bytes());
+    }
+
+    let vec3_len = n * 12;
+    let json = format!(
+        concat!(
+            "{{",
+            "\"asset\":{{\"version\":\"2.0\",\"generator\":\"rust-wasm splat export\"}},",
+            "\"extensionsUsed\":[\"{ext}\"],",
+            "\"scene\":0,",
+            "\"scenes\":[{{\"nodes\":[0]}}],",
+            "\"nodes\":[{{\"mesh\":0}}],",
+            "\"meshes\":[{{\"primitives\":[{{",
+            "\"mode\":0,",
+            "\"attributes\":{{\"POSITION\":0,\"_COVARIANCE_A\":1,\"_COVARIANCE_B\":2,\"COLOR_0\":3}},",
+            "\"extensions\":{{\"{ext}\":{{\"covariance\":[1,2]}}}}",
+            "}}]}}],",
+            "\"buffers\":[{{\"uri\":\"{uri}\",\"byteLength\":{bin_len}}}],",
+            "\"bufferViews\":[",
+            "{{\"buffer\":0,\"byteOffset\":0,\"byteLength\":{vec3_len}}},",
+            "{{\"buffer\":0,\"byteOffset\":{cov_a},\"byteLength\":{vec3_len}}},",
+            "{{\"buffer\":0,\"byteOffset\":{cov_b},\"byteLength\":{vec3_len}}},",
+            "{{\"buffer\":0,\"byteOffset\":{color},\"byteLength\":{color_len}}}",
+            "],",
+            "\"accessors\":[",
+            "{{\"bufferView\":0,\"componentType\":5126,\"count\":{n},\"type\":\"VEC3\",\"min\":{min},\"max\":{max}}},",
+            "{{\"bufferView\":1,\"componentType\":5126,\"count\":{n},\"type\":\"VEC3\"}},",
+            "{{\"bufferView\":2,\"componentType\":5126,\"count\":{n},\"type\":\"VEC3\"}},",
+            "{{\"bufferView\":3,\"componentType\":5121,\"normalized\":true,\"count\":{n},\"type\":\"VEC4\"}}",
+            "]",
+            "}}"
+        ),
+        ext = GLTF_SPLAT_EXTENSION,
+        uri = GLTF_BUFFER_URI,
+        bin_len = bin.len(),
+        vec3_len = vec3_len,
+        cov_a = cov_a_offset,
+        cov_b = cov_b_offset,
+        color = color_offset,
+        color_len = n * 4,
+        n = n,
+        min = json_vec3(buffers.bbox_min),
+        max = json_vec3(buffers.bbox_max),
+    );
+
+    (json.into_bytes(), bin)
+}
+
+fn json_vec3(v: [f32; 3]) -> String {
+    // JSON has no representation for inf/NaN.
+    let f = |x: f32| if x.is_finite() { x } else { 0.0 };
+    format!("[{},{},{}]", f(v[0]), f(v[1]), f(v[2]))
+}
+
+/// Size of one record in the antimatter15 `.splat` format.
+pub const SPLAT_RECORD_BYTES: usize = 32;
+
+/// Length checks for [`parse_splat_file_core_with_options`]. `Default` matches
+/// [`parse_splat_file_core`].
+#[derive(Debug, Clone, Default)]
+pub struct SplatFileOptions {
+    /// Accept a zero-byte file as an empty scene. Off by default: an empty `.splat` is
+    /// almost always a failed download or write.
+    pub allow_empty: bool,
+    /// Reject files holding more than this many records.
+    pub max_count: Option<usize>,
+}
+
+/// Parses an antimatter15 `.splat` file: 32-byte records of
+/// `position: 3 x f32`, `scale: 3 x f32` (linear), `rgba: 4 x u8`, `rot: 4 x u8` (w, x, y, z),
+/// where each rotation byte maps to `(b - 128) / 128`.
+///
+/// The floats are little-endian per the format and are decoded as such regardless of the host;
+/// the byte fields are endian-neutral. `format` is reported as `BinaryLittleEndian`.
+///
+/// Rejects an empty file and a size that is not a whole number of records (a truncated file
+/// would otherwise silently lose its last splat); see [`parse_splat_file_core_with_options`].
+pub fn parse_splat_file_core(bytes: &[u8]) -> Result<SplatPlyBuffersCore, PlyError> {
+    parse_splat_file_core_with_options(bytes, &SplatFileOptions::default())
+}
+
+/// Like [`parse_splat_file_core`], with the length checks configured by `opts`.
+pub fn parse_splat_file_core_with_options(
+    bytes: &[u8],
+    opts: &SplatFileOptions,
+) -> Result<SplatPlyBuffersCore, PlyError> {
+    if bytes.is_empty() && !opts.allow_empty {
+        return Err(PlyError::msg(PlyErrorKind::InvalidData, ".splat: file is empty"));
+    }
+    let trailing = bytes.len() % SPLAT_RECORD_BYTES;
+    if trailing != 0 {
+        return Err(PlyError::MsgOwned(
+            PlyErrorKind::InvalidData,
+            if bytes.len() < SPLAT_RECORD_BYTES {
+                format!(".splat: file size {} is shorter than one {SPLAT_RECORD_BYTES}-byte record", bytes.len())
+            } else {
+                format!(
+                    ".splat: file size {} is not a multiple of {SPLAT_RECORD_BYTES} bytes \
+                     ({trailing} trailing bytes, file likely truncated)",
+                    bytes.len()
+                )
+            },
+        ));
+    }
+    let count = bytes.len() / SPLAT_RECORD_BYTES;
+    if let Some(max) = opts.max_count.filter(|&max| count > max) {
+        return Err(PlyError::MsgOwned(
+            PlyErrorKind::InvalidArgument,
+            format!(".splat: {count} records exceed max_count {max}"),
+        ));
+    }
+
+    let mut center: Vec<f32> = Vec::with_capacity(count * 3);
+    let mut covariance: Vec<f32> = Vec::with_capacity(count * 6);
+    let mut rgba: Vec<u32> = Vec::with_capacity(count);
+
+    for rec in bytes.chunks_exact(SPLAT_RECORD_BYTES) {
+        let f = |k: usize| f32::from_le_bytes([rec[k * 4], rec[k * 4 + 1], rec[k * 4 + 2], rec[k * 4 + 3]]);
+        center.extend_from_slice(&[f(0), f(1), f(2)]);
+
+        let q = |b: u8| (b as f32 - 128.0) / 128.0;
+        let (qw, qx, qy, qz) = (q(rec[28]), q(rec[29]), q(rec[30]), q(rec[31]));
+        covariance.extend_from_slice(&covariance_from_quat_scale(qx, qy, qz, qw, f(3), f(4), f(5)));
+
+        rgba.push(rgba_to_u32(rec[24] as u32, rec[25] as u32, rec[26] as u32, rec[27] as u32));
+    }
+
+    let mut out = SplatPlyBuffersCore {
+        count: count as u32,
+        format: PlyFormat::BinaryLittleEndian,
+        center: center.into_boxed_slice(),
+        covariance: covariance.into_boxed_slice(),
+        rgba: rgba.into_boxed_slice(),
+        bbox_min: [0.0; 3],
+        bbox_max: [0.0; 3],
+        bsphere_center: [0.0; 3],
+        bsphere_radius: 0.0,
+        centroid: [0.0; 3],
+        center_f64: None,
+        sh: Box::new([]),
+        sh_degree: 0,
+        scales: Box::new([]),
+        quats: Box::new([]),
+        element_name: String::new(),
+        antialiased: false,
+        scale_mode: Some(ScaleMode::Linear),
+    };
+    out.recompute_bounds();
+    Ok(out)
+}
+
+/// Encodes splats as an antimatter15 `.splat` file, the inverse of [`parse_splat_file_core`].
+///
+/// Each splat's scale and rotation come from `scales`/`quats` when the buffers keep them
+/// (see `ParseOptionsCore::keep_raw`); otherwise they are recovered by eigen-decomposition
+/// ([`covariance_to_scale_quat`]), a pair that is generally not the one the source file had
+/// but reproduces the same covariance. Rotation components are quantized to
+/// `round(q · 128) + 128` (clamped to a byte), so covariances round-trip only approximately.
+/// Centers and colors are exact; SH is dropped.
+pub fn write_splat(buffers: &SplatPlyBuffersCore) -> Vec<u8> {
+    let n = buffers.count as usize;
+    let mut out = Vec::with_capacity(n * SPLAT_RECORD_BYTES);
+    for i in 0..n {
+        let rec = SplatRecord::from_buffers(buffers, i);
+        for v in rec.center.iter().chain(rec.scale.iter()) {
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+        out.extend_from_slice(&rec.rgba.to_le_bytes());
+        let [qx, qy, qz, qw] = rec.quat;
+        let len = (qx * qx + qy * qy + qz * qz + qw * qw).sqrt();
+        for q in [qw, qx, qy, qz] {
+            out.push((q / len * 128.0 + 128.0).round().clamp(0.0, 255.0) as u8);
+        }
+    }
+    out
+}
+
+/// Texture width used by the antimatter15 WebGL viewer.
+pub const WEBGL_SPLAT_TEXTURE_WIDTH: u32 = 2048;
+
+/// Packs splats into the RGBA32UI data texture of the antimatter15 WebGL viewer:
+/// `(texels, width, height)`, with `width` = [`WEBGL_SPLAT_TEXTURE_WIDTH`] and
+/// `height = ceil(2N / width)`; `texels` holds `width * height * 4` words.
+///
+/// Splat `i` fills the two texels at words `8i..8i + 8`:
+/// - words 0..3: center x, y, z as f32 bits; word 3 is unused (0);
+/// - word 4: `packHalf2x16(4·m11, 4·m12)` — half of the first value in the low 16 bits;
+/// - word 5: `packHalf2x16(4·m13, 4·m22)`;
+/// - word 6: `packHalf2x16(4·m23, 4·m33)`;
+/// - word 7: the packed rgba (r in the low byte), i.e. the color bytes in r, g, b, a order.
+///
+/// The factor 4 is the viewer's convention (its shader expects `4Σ`). Halves are rounded to
+/// nearest, where the viewer's own packer truncates, so values can differ in the last bit.
+pub fn to_webgl_splat_texture(buffers: &SplatPlyBuffersCore) -> (Box<[u32]>, u32, u32) {
+    let n = buffers.count as usize;
+    let width = WEBGL_SPLAT_TEXTURE_WIDTH;
+    let height = (2 * n as u32).div_ceil(width);
+    let mut out = vec![0u32; (width * height) as usize * 4];
+    let half2 = |a: f32, b: f32| {
+        f32_to_f16_bits(4.0 * a) as u32 | (f32_to_f16_bits(4.0 * b) as u32) << 16
+    };
+    for (i, words) in out.chunks_exact_mut(8).take(n).enumerate() {
+        let c = &buffers.center[i * 3..i * 3 + 3];
+        let m = &buffers.covariance[i * 6..i * 6 + 6];
+        words[0] = c[0].to_bits();
+        words[1] = c[1].to_bits();
+        words[2] = c[2].to_bits();
+        words[4] = half2(m[0], m[1]);
+        words[5] = half2(m[2], m[3]);
+        words[6] = half2(m[4], m[5]);
+        words[7] = buffers.rgba[i];
+    }
+    (out.into_boxed_slice(), width, height)
+}
+
+/// Decodes standard base64 (`A–Z a–z 0–9 + /`), with or without `=` padding. Whitespace is
+/// skipped so wrapped text decodes too. A `data:...;base64,` prefix is stripped first.
+pub fn decode_base64(s: &str) -> Result<Vec<u8>, PlyError> {
+    let s = match s.trim_start().strip_prefix("data:") {
+        Some(uri) => {
+            let (meta, data) = uri
+                .split_once(',')
+                .ok_or(PlyError::msg(PlyErrorKind::InvalidData, "base64: data URI has no ',' before the payload"))?;
+            if !meta.ends_with(";base64") {
+                return Err(PlyError::msg(PlyErrorKind::InvalidData, "base64: data URI is not base64-encoded"));
+            }
+            data
+        }
+        None => s,
+    };
+    let sextet = |c: u8| -> Result<u32, PlyError> {
+        Ok(match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => {
+                let msg = format!("base64: invalid character {:?}", c as char);
+                return Err(PlyError::MsgOwned(PlyErrorKind::InvalidData, msg));
+            }
+        } as u32)
+    };
+
+    let digits: Vec<u8> = s.bytes().filter(|c| !c.is_ascii_whitespace()).collect();
+    let digits = digits.strip_suffix(b"==").or(digits.strip_suffix(b"=")).unwrap_or(&digits);
+    let sextets = digits.iter().map(|&c| sextet(c)).collect::<Result<Vec<u32>, PlyError>>()?;
+    if sextets.len() % 4 == 1 {
+        return Err(PlyError::msg(PlyErrorKind::Truncated, "base64: truncated input"));
+    }
+    let mut out = Vec::with_capacity(sextets.len() / 4 * 3 + 2);
+    for chunk in sextets.chunks(4) {
+        let mut acc = 0u32;
+        for &v in chunk {
+            acc = (acc << 6) | v;
+        }
+        // A short final chunk of n digits carries n - 1 bytes.
+        acc <<= 6 * (4 - chunk.len()) as u32;
+        out.extend_from_slice(&acc.to_be_bytes()[1..chunk.len()]);
+    }
+    Ok(out)
+}
+
+/// Parses a base64-encoded splat PLY (optionally a `data:` URI), see [`decode_base64`].
+pub fn parse_splat_ply_base64_core(s: &str, opts: &ParseOptionsCore) -> Result<SplatPlyBuffersCore, PlyError> {
+    parse_splat_ply_core_with_options(&decode_base64(s)?, opts)
+}
+
+/// One splat in the form the writers encode: linear scale and an `[x, y, z, w]` quaternion,
+/// taken from `scales`/`quats` when the buffers keep them and otherwise recovered from the
+/// covariance, plus its higher-order SH run.
+pub(crate) struct SplatRecord<'a> {
+    pub(crate) center: [f32; 3],
+    pub(crate) scale: [f32; 3],
+    pub(crate) quat: [f32; 4],
+    pub(crate) rgba: u32,
+    pub(crate) sh: &'a [f32],
+}
+
+impl SplatRecord<'_> {
+    pub(crate) fn from_buffers(buffers: &SplatPlyBuffersCore, i: usize) -> SplatRecord<'_> {
+        let sh_len = sh_rest_len(buffers.sh_degree);
+        let c = &buffers.center[i * 3..i * 3 + 3];
+        let (scale, quat) = if buffers.scales.is_empty() {
+            covariance_to_scale_quat(&buffers.covariance[i * 6..i * 6 + 6])
+        } else {
+            let (s, q) = (&buffers.scales[i * 3..i * 3 + 3], &buffers.quats[i * 4..i * 4 + 4]);
+            ([s[0], s[1], s[2]], [q[0], q[1], q[2], q[3]])
+        };
+        SplatRecord {
+            center: [c[0], c[1], c[2]],
+            scale,
+            quat,
+            rgba: buffers.rgba[i],
+            sh: &buffers.sh[i * sh_len..(i + 1) * sh_len],
+        }
+    }
+
+    fn channel(&self, k: u32) -> f32 {
+        ((self.rgba >> (8 * k)) & 255) as f32
+    }
+
+    /// Channel `k` in [0, 1], at the center of its 1/255 bucket so the parser's
+    /// `floor(v * 255)` lands back on the same byte.
+    fn channel01(&self, k: u32) -> f32 {
+        (self.channel(k) + 0.5) / 255.0
+    }
+
+    /// Value of the vertex property `name`, encoded the way `parse_splat_ply_core` decodes it
+    /// by default (log scales, logit opacity, SH DC color, `f_rest_*` from `sh`). Unknown
+    /// properties, and SH coefficients beyond the scene's degree, are written as 0.
+    pub(crate) fn property_value(&self, name: &str, ty: PlyScalarType) -> f64 {
+        let name = name.to_lowercase();
+        let is = |names: &[&str]| names.contains(&name.as_str());
+        let [qx, qy, qz, qw] = self.quat;
+        let v = if is(X_NAMES) {
+            self.center[0]
+        } else if is(Y_NAMES) {
+            self.center[1]
+        } else if is(Z_NAMES) {
+            self.center[2]
+        } else if is(SCALE_0_NAMES) {
+            self.scale[0].ln()
+        } else if is(SCALE_1_NAMES) {
+            self.scale[1].ln()
+        } else if is(SCALE_2_NAMES) {
+            self.scale[2].ln()
+        } else if let Some(k) = ROT_WXYZ_NAMES.iter().position(|n| *n == name) {
+            [qw, qx, qy, qz][k]
+        } else if let Some(k) = ROT_XYZW_NAMES.iter().position(|n| *n == name) {
+            self.quat[k]
+        } else if is(OPACITY_NAMES) {
+            // Opaque alpha sits above 1 and has no logit: use the largest value the parser still
+            // reads as a logit, whose sigmoid rounds to 1.0 in f32.
+            let a = self.channel01(3);
+            if a >= 1.0 { MAX_USEFUL_LOGIT as f32 } else { (a / (1.0 - a)).ln() }
+        } else if let Some(k) = [RED_NAMES, GREEN_NAMES, BLUE_NAMES].iter().position(|n| is(n)) {
+            let c = self.channel(k as u32);
+            if ty.is_probably_byte_color() { c } else { self.channel01(k as u32) }
+        } else if let Some(k) = DC_NAMES.iter().position(|n| is(n)) {
+            (self.channel01(k as u32) - 0.5) / SH_C0
+        } else if let Some(k) = name.strip_prefix(SH_REST_PREFIX).and_then(|k| k.parse::<usize>().ok()) {
+            self.sh.get(k).copied().unwrap_or(0.0)
+        } else {
+            0.0
+        };
+        v as f64
+    }
+}
+
+/// Appends `v` as a binary PLY scalar of type `ty`, rounding and saturating integer types.
+pub(crate) fn write_scalar(out: &mut Vec<u8>, ty: PlyScalarType, v: f64, little: bool) {
+    macro_rules! put {
+        ($x:expr) => {
+            if little {
+                out.extend_from_slice(&$x.to_le_bytes())
+            } else {
+                out.extend_from_slice(&$x.to_be_bytes())
+            }
+        };
+    }
+    match ty {
+        PlyScalarType::Char => put!(v.round() as i8),
+        PlyScalarType::UChar => put!(v.round() as u8),
+        PlyScalarType::Short => put!(v.round() as i16),
+        PlyScalarType::UShort => put!(v.round() as u16),
+        PlyScalarType::Int => put!(v.round() as i32),
+        PlyScalarType::UInt => put!(v.round() as u32),
+        PlyScalarType::Float => put!(v as f32),
+        PlyScalarType::Double => put!(v),
+    }
+}
+
+fn io_error(e: std::io::Error) -> PlyError {
+    PlyError::MsgOwned(PlyErrorKind::Io, format!("PLY: io error: {e}"))
+}
+
+/// Appends `new` to the binary splat PLY at `path`, bumping the vertex count in the header.
+///
+/// The new splats are encoded into the file's existing vertex property layout (see
+/// `SplatRecord::property_value` for the conventions; properties the parser doesn't know are
+/// written as 0). Constraints: the file must be binary, have only fixed-size (non-list)
+/// properties, and `vertex` must be its last element so the records can go at the end.
+///
+/// Only the header is read to validate the file against its size. The header is
+/// variable-length text, so it's rewritten with the new count: if its length doesn't change,
+/// it's overwritten in place and only the new records are appended; otherwise the whole file
+/// is read and rewritten.
+pub fn append_splats_to_ply(path: &Path, new: &SplatPlyBuffersCore) -> Result<(), PlyError> {
+    let mut file = std::fs::OpenOptions::new().read(true).write(true).open(path).map_err(io_error)?;
+    let file_len = file.metadata().map_err(io_error)?.len();
+    let header_bytes = read_header_bytes(&mut std::io::BufReader::new(&file))?;
+    let header = parse_header(&header_bytes)?;
+    let little = match header.format {
+        PlyFormat::BinaryLittleEndian => true,
+        PlyFormat::BinaryBigEndian => false,
+        PlyFormat::Ascii => {
+            return Err(PlyError::msg(PlyErrorKind::Unsupported, "PLY append: only binary files are supported"));
+        }
+    };
+    let last = header
+        .elements
+        .last()
+        .filter(|e| e.name.to_lowercase() == "vertex")
+        .ok_or_else(|| PlyError::msg(PlyErrorKind::Unsupported, "PLY append: \"vertex\" must be the last element"))?;
+
+    let overflow = || PlyError::msg(PlyErrorKind::InvalidData, "PLY append: header describes more data than fits");
+    let mut data_len = 0u64;
+    for el in header.elements.iter() {
+        let stride = binary_stride(el)
+            .ok_or_else(|| PlyError::msg(PlyErrorKind::Unsupported, "PLY append: list properties are not supported"))?;
+        let len = (el.count as u64).checked_mul(stride as u64).ok_or_else(overflow)?;
+        data_len = data_len.checked_add(len).ok_or_else(overflow)?;
+    }
+    if (header.data_offset as u64).checked_add(data_len) != Some(file_len) {
+        return Err(PlyError::MsgOwned(
+            PlyErrorKind::InvalidData,
+            format!(
+                "PLY append: header describes {} data bytes but the file has {}",
+                data_len,
+                file_len - header.data_offset as u64
+            ),
+        ));
+    }
+
+    let mut records: Vec<u8> = Vec::new();
+    for i in 0..new.count as usize {
+        let rec = SplatRecord::from_buffers(new, i);
+        for p in last.properties.iter() {
+            if let PlyProperty::Scalar { name, ty } = p {
+                write_scalar(&mut records, *ty, rec.property_value(name, *ty), little);
+            }
+        }
+    }
+
+    // Rewrite only the `element vertex N` line, keeping comments and line endings intact.
+    let old_header = core::str::from_utf8(&header_bytes)
+        .map_err(|_| PlyError::msg(PlyErrorKind::Header, "PLY: header is not valid utf-8"))?;
+    let new_count = last.count + new.count as usize;
+    let mut new_header = String::with_capacity(old_header.len() + 8);
+    for line in old_header.split_inclusive('\n') {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        if tokens.len() == 3 && tokens[0] == "element" && tokens[1].to_lowercase() == "vertex" {
+            let ending = if line.ends_with("\r\n") { "\r\n" } else { "\n" };
+            new_header.push_str(&format!("element {} {new_count}{ending}", tokens[1]));
+        } else {
+            new_header.push_str(line);
+        }
+    }
+
+    if new_header.len() == old_header.len() {
+        file.seek(SeekFrom::Start(0)).map_err(io_error)?;
+        file.write_all(new_header.as_bytes()).map_err(io_error)?;
+        file.seek(SeekFrom::End(0)).map_err(io_error)?;
+        file.write_all(&records).map_err(io_error)?;
+    } else {
+        let mut out = new_header.into_bytes();
+        file.seek(SeekFrom::Start(header.data_offset as u64)).map_err(io_error)?;
+        file.read_to_end(&mut out).map_err(io_error)?;
+        out.extend_from_slice(&records);
+        file.seek(SeekFrom::Start(0)).map_err(io_error)?;
+        file.write_all(&out).map_err(io_error)?;
+    }
+    Ok(())
+}
+
+/// Format and vertex properties (in order) a PLY writer emits. Values are encoded as in
+/// [`append_splats_to_ply`]; properties the parser doesn't know are written as 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlyWriteLayout {
+    pub format: PlyFormat,
+    pub properties: Vec<(String, PlyScalarType)>,
+}
+
+impl PlyWriteLayout {
+    /// The reference 3DGS layout: `x, y, z, f_dc_0..2, opacity, scale_0..2, rot_0..3`,
+    /// all `float`.
+    pub fn inria(format: PlyFormat) -> PlyWriteLayout {
+        let names = [
+            "x", "y", "z", "f_dc_0", "f_dc_1", "f_dc_2", "opacity", "scale_0", "scale_1", "scale_2",
+            "rot_0", "rot_1", "rot_2", "rot_3",
+        ];
+        PlyWriteLayout {
+            format,
+            properties: names.iter().map(|n| (n.to_string(), PlyScalarType::Float)).collect(),
+        }
+    }
+}
+
+/// Writes the splats at `indices` (in that order) as a standalone PLY in `layout`.
+/// Fails if an index is out of range.
+pub fn write_subset_ply(
+    buffers: &SplatPlyBuffersCore,
+    indices: &[u32],
+    layout: &PlyWriteLayout,
+) -> Result<Vec<u8>, PlyError> {
+    write_ply(buffers, indices, layout, None)
+}
+
+/// Writes the whole scene as an ASCII splat PLY with the properties of `layout` (its
+/// `format` is ignored). Floating-point values get `precision` digits after the decimal point,
+/// or the shortest representation that round-trips when `None`. Scale and rotation are
+/// recovered from the covariance (see [`covariance_to_scale_quat`]).
+pub fn write_splat_ply_ascii(
+    buffers: &SplatPlyBuffersCore,
+    layout: &PlyWriteLayout,
+    precision: Option<usize>,
+) -> Result<String, PlyError> {
+    let layout = PlyWriteLayout {
+        format: PlyFormat::Ascii,
+        properties: layout.properties.clone(),
+    };
+    let indices: Vec<u32> = (0..buffers.count).collect();
+    let bytes = write_ply(buffers, &indices, &layout, precision)?;
+    // Header and values are all ASCII.
+    Ok(String::from_utf8(bytes).expect("ascii PLY output is valid utf-8"))
+}
+
+fn write_ply(
+    buffers: &SplatPlyBuffersCore,
+    indices: &[u32],
+    layout: &PlyWriteLayout,
+    precision: Option<usize>,
+) -> Result<Vec<u8>, PlyError> {
+    if let Some(&bad) = indices.iter().find(|&&i| i >= buffers.count) {
+        return Err(PlyError::MsgOwned(
+            PlyErrorKind::InvalidArgument,
+            format!("PLY write: index {bad} out of range for {} splats", buffers.count),
+        ));
+    }
+
+    let mut builder = PlyHeaderBuilder::new(layout.format).element("vertex", indices.len());
+    for (name, ty) in layout.properties.iter() {
+        builder = builder.scalar(name, *ty);
+    }
+    let (header, _) = builder.build()?;
+    let mut out = header.into_bytes();
+
+    for &i in indices {
+        let rec = SplatRecord::from_buffers(buffers, i as usize);
+        match layout.format {
+            PlyFormat::BinaryLittleEndian | PlyFormat::BinaryBigEndian => {
+                let little = layout.format == PlyFormat::BinaryLittleEndian;
+                for (name, ty) in layout.properties.iter() {
+                    write_scalar(&mut out, *ty, rec.property_value(name, *ty), little);
+                }
+            }
+            PlyFormat::Ascii => {
+                let values: Vec<String> = layout
+                    .properties
+                    .iter()
+                    .map(|(name, ty)| ascii_scalar(*ty, rec.property_value(name, *ty), precision))
+                    .collect();
+                out.extend_from_slice(values.join(" ").as_bytes());
+                out.push(b'\n');
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// A valid splat PLY of `count` random splats in `format` ([`PlyWriteLayout::inria`]),
+/// identical for the same `seed`. Centers are in [-1, 1]³, scales in [e⁻⁵, e⁻²], rotations
+/// uniform, opacity in [0.2, 1) and colors uniform. Meant for tests.
+pub fn generate_test_ply(count: usize, format: PlyFormat, seed: u64) -> Vec<u8> {
+    let mut rng = SplitMix64::new(seed);
+    let mut builder = SplatBuilder::with_capacity(count);
+    for _ in 0..count {
+        let center = [rng.range_f32(-1.0, 1.0), rng.range_f32(-1.0, 1.0), rng.range_f32(-1.0, 1.0)];
+        let [sx, sy, sz] = [(); 3].map(|_| rng.range_f32(-5.0, -2.0).exp());
+
+        // Uniform rotation (Shoemake).
+        let (u1, u2, u3) = (rng.next_f32(), rng.next_f32(), rng.next_f32());
+        let tau = std::f32::consts::TAU;
+        let (a, b) = ((1.0 - u1).sqrt(), u1.sqrt());
+        let [qx, qy, qz, qw] = [a * (tau * u2).sin(), a * (tau * u2).cos(), b * (tau * u3).sin(), b * (tau * u3).cos()];
+
+        let rgba = rgba_to_u32(
+            (rng.next_u64() & 255) as u32,
+            (rng.next_u64() & 255) as u32,
+            (rng.next_u64() & 255) as u32,
+            (rng.range_f32(0.2, 1.0) * 255.0) as u32,
+        );
+        builder.push_splat(center, covariance_from_quat_scale(qx, qy, qz, qw, sx, sy, sz), rgba);
+    }
+    let buffers = builder.finish();
+    let indices: Vec<u32> = (0..count as u32).collect();
+    write_subset_ply(&buffers, &indices, &PlyWriteLayout::inria(format))
+        .expect("indices are in range and the inria layout is valid")
+}
+
+/// `v` as ASCII PLY text for type `ty`, rounding and saturating integer types.
+fn ascii_scalar(ty: PlyScalarType, v: f64, precision: Option<usize>) -> String {
+    match ty {
+        PlyScalarType::Float | PlyScalarType::Double if let Some(p) = precision => format!("{v:.p$}"),
+        PlyScalarType::Char => (v.round() as i8).to_string(),
+        PlyScalarType::UChar => (v.round() as u8).to_string(),
+        PlyScalarType::Short => (v.round() as i16).to_string(),
+        PlyScalarType::UShort => (v.round() as u16).to_string(),
+        PlyScalarType::Int => (v.round() as i32).to_string(),
+        PlyScalarType::UInt => (v.round() as u32).to_string(),
+        PlyScalarType::Float => (v as f32).to_string(),
+        PlyScalarType::Double => v.to_string(),
+    }
+}
+
+/// Re-encodes an ASCII or `binary_big_endian` PLY as `binary_little_endian`, e.g. to
+/// preprocess slow-to-parse files once. Every element is kept with its comments and declared
+/// property types; each record's scalars are packed in declared order. Only scalar properties
+/// are supported, and an ASCII integer that doesn't fit its declared type is an error.
+/// Binary little-endian input is returned unchanged.
+pub fn convert_ply_to_binary_le(bytes: &[u8]) -> Result<Vec<u8>, PlyError> {
+    let header = parse_header(bytes)?;
+    if header.format == PlyFormat::BinaryLittleEndian {
+        return Ok(bytes.to_vec());
+    }
+
+    let mut builder = PlyHeaderBuilder::new(PlyFormat::BinaryLittleEndian);
+    for c in header.comments.iter() {
+        builder = builder.comment(c);
+    }
+    let mut types: Vec<Vec<PlyScalarType>> = Vec::with_capacity(header.elements.len());
+    for el in header.elements.iter() {
+        builder = builder.element(&el.name, el.count);
+        let mut tys = Vec::with_capacity(el.properties.len());
+        for p in el.properties.iter() {
+            match p {
+                PlyProperty::Scalar { name, ty } => {
+                    builder = builder.scalar(name, *ty);
+                    tys.push(*ty);
+                }
+                PlyProperty::List { .. } => {
+                    return Err(PlyError::MsgOwned(
+                        PlyErrorKind::Unsupported,
+                        format!("PLY convert: element \"{}\" has list properties", el.name),
+                    ));
+                }
+            }
+        }
+        types.push(tys);
+    }
+    let (text, _) = builder.build()?;
+    let mut out = Vec::with_capacity(bytes.len());
+    out.extend_from_slice(text.as_bytes());
+
+    if header.format == PlyFormat::BinaryBigEndian {
+        // Same layout, so each scalar only has its bytes reversed.
+        let mut pos = header.data_offset;
+        for (el, tys) in header.elements.iter().zip(&types) {
+            for _ in 0..el.count {
+                for &ty in tys {
+                    let end = pos + ty.size_bytes();
+                    let scalar = bytes.get(pos..end).ok_or_else(|| {
+                        PlyError::MsgOwned(
+                            PlyErrorKind::Truncated,
+                            format!("PLY convert: element \"{}\" runs past the end of the data", el.name),
+                        )
+                    })?;
+                    out.extend(scalar.iter().rev());
+                    pos = end;
+                }
+            }
+        }
+        return Ok(out);
+    }
+
+    let data = core::str::from_utf8(&bytes[header.data_offset..])
+        .map_err(|_| PlyError::msg(PlyErrorKind::InvalidData, "PLY ASCII: data is not valid utf-8"))?;
+    // One line per record, whatever the element.
+    let mut lines = data.lines().filter(|l| !l.trim().is_empty());
+    for (el, tys) in header.elements.iter().zip(&types) {
+        for _ in 0..el.count {
+            let line = lines
+                .next()
+                .ok_or(PlyError::msg(PlyErrorKind::Truncated, "PLY ASCII: not enough vertex lines"))?;
+            let mut parts = line.split_whitespace();
+            for &ty in tys {
+                let s = parts
+                    .next()
+                    .ok_or(PlyError::msg(PlyErrorKind::Truncated, "PLY ASCII: missing column"))?;
+                // Floats parse as f32 like the decoder does; integers are exact in f64.
+                let v = if ty == PlyScalarType::Float {
+                    s.parse::<f32>().ok().map(|v| v as f64)
+                } else {
+                    s.parse::<f64>().ok()
+                };
+                let v = v.ok_or(PlyError::msg(PlyErrorKind::InvalidData, "PLY ASCII: failed to parse number"))?;
+                if let Some((min, max)) = ty.integer_range()
+                    && !(min..=max).contains(&v.round())
+                {
+                    return Err(PlyError::MsgOwned(
+                        PlyErrorKind::InvalidData,
+                        format!("PLY convert: {s} does not fit a {} property", ty.as_str()),
+                    ));
+                }
+                write_scalar(&mut out, ty, v, true);
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Output formats a tool can offer, in increasing compactness.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// ASCII PLY: human-readable and diffable, but large and slow to parse.
+    Ascii,
+    /// `binary_little_endian` PLY: lossless, every property kept.
+    BinaryLE,
+    /// antimatter15 `.splat`: 32 bytes per splat, quantized rotation and color, no SH.
+    Splat,
+}
+
+/// Suggested output format for a scene of `count` splats, among those this crate writes:
+/// ASCII up to 1 000 (debugging), binary PLY up to 100 000 (lossless while still cheap to
+/// move), `.splat` beyond (size and fast web loading dominate).
+pub fn recommend_output_format(count: u32) -> OutputFormat {
+    match count {
+        0..=1_000 => OutputFormat::Ascii,
+        1_001..=100_000 => OutputFormat::BinaryLE,
+        _ => OutputFormat::Splat,
+    }
+}
+
+/// Maps f32 bits to a u32 whose unsigned order matches the float order, so nearby values
+/// get nearby keys.
+fn ordered_key(v: f32) -> u32 {
+    let bits = v.to_bits();
+    if is_bit_set_u32(bits, 31) {
+        !bits
+    } else {
+        set_bit_u32(bits, 31)
+    }
+}
+
+fn from_ordered_key(key: u32) -> f32 {
+    if is_bit_set_u32(key, 31) {
+        f32::from_bits(key & 0x7FFF_FFFF)
+    } else {
+        f32::from_bits(!key)
+    }
+}
+
+fn write_varint(out: &mut Vec<u8>, mut v: u32) {
+    while v >= 0x80 {
+        out.push((v as u8 & 0x7F) | 0x80);
+        v >>= 7;
+    }
+    out.push(v as u8);
+}
+
+fn read_varint(bytes: &[u8], pos: &mut usize) -> Result<u32, PlyError> {
+    let mut v = 0u32;
+    for shift in (0..35).step_by(7) {
+        let b = *bytes
+            .get(*pos)
+            .ok_or_else(|| PlyError::msg(PlyErrorKind::Truncated, "delta positions: truncated varint"))?;
+        *pos += 1;
+        v |= ((b & 0x7F) as u32) << shift;
+        if !is_bit_set_u32(b as u32, 7) {
+            return Ok(v);
+        }
+    }
+    Err(PlyError::msg(PlyErrorKind::InvalidData, "delta positions: varint longer than 5 bytes"))
+}
+
+/// Losslessly packs `centers` (3N f32) as per-axis deltas of variable-length integers.
+///
+/// Wire format (all integers are LEB128 varints: 7 bits per byte, low bits first,
+/// high bit set on every byte but the last):
+/// - `N`, the number of points;
+/// - `N` values for x, then `N` for y, then `N` for z. Each is
+///   `zigzag(key[i] - key[i - 1])` (wrapping, `key[-1] = 0`), where `key` maps the f32 bits
+///   to an order-preserving u32 (see [`crate::bitops_core::zigzag_encode_i32`] for `zigzag`).
+///
+/// Input order is kept; sorting the points first (e.g. along x) makes the deltas, and so
+/// the output, much smaller.
+pub fn encode_positions_delta(centers: &[f32]) -> Vec<u8> {
+    let n = centers.len() / 3;
+    let mut out = Vec::with_capacity(n * 3 * 2 + 5);
+    write_varint(&mut out, n as u32);
+    for axis in 0..3 {
+        let mut prev = 0u32;
+        for c in centers.chunks_exact(3) {
+            let key = ordered_key(c[axis]);
+            write_varint(&mut out, zigzag_encode_i32(key.wrapping_sub(prev) as i32));
+            prev = key;
+        }
+    }
+    out
+}
+
+/// Inverse of [`encode_positions_delta`], returning 3N f32 centers.
+pub fn decode_positions_delta(bytes: &[u8]) -> Result<Box<[f32]>, PlyError> {
+    let mut pos = 0usize;
+    let n = read_varint(bytes, &mut pos)? as usize;
+    // Every value takes at least one byte.
+    if n.saturating_mul(3) > bytes.len() - pos {
+        return Err(PlyError::msg(PlyErrorKind::Truncated, "delta positions: point count exceeds data"));
+    }
+    let mut centers = vec![0.0f32; n * 3];
+    for axis in 0..3 {
+        let mut prev = 0u32;
+        for c in centers.chunks_exact_mut(3) {
+            let key = prev.wrapping_add(zigzag_decode_u32(read_varint(bytes, &mut pos)?) as u32);
+            c[axis] = from_ordered_key(key);
+            prev = key;
+        }
+    }
+    if pos != bytes.len() {
+        return Err(PlyError::msg(PlyErrorKind::InvalidData, "delta positions: trailing bytes after the last point"));
+    }
+    Ok(centers.into_boxed_slice())
+}
+
+/// Leading bytes of a [`serialize_buffers`] cache.
+pub const CACHE_MAGIC: [u8; 4] = *b"VNYS";
+
+/// Layout version of [`serialize_buffers`]; bumped on any change so stale caches are rejected.
+pub const CACHE_FORMAT_VERSION: u32 = 1;
+
+/// Serializes parsed buffers into a cache that [`deserialize_buffers`] restores exactly, to
+/// skip re-parsing a PLY. Every value is written little-endian regardless of the host, so a
+/// cache moves safely between machines.
+///
+/// Layout: [`CACHE_MAGIC`], then as u32: [`CACHE_FORMAT_VERSION`], `count`, `sh_degree`,
+/// the `format` (0 ascii, 1 binary LE, 2 binary BE) and a flag word (bit 0: `center_f64`
+/// follows, bit 1: `scales` and `quats` follow); then the f32 `bbox_min`, `bbox_max`,
+/// `center`, `covariance`, the u32 `rgba`, the f64 `center_f64` if flagged, the f32 `sh`, and
+/// the f32 `scales` and `quats` if flagged.
+pub fn serialize_buffers(buffers: &SplatPlyBuffersCore) -> Vec<u8> {
+    let format = match buffers.format {
+        PlyFormat::Ascii => 0u32,
+        PlyFormat::BinaryLittleEndian => 1,
+        PlyFormat::BinaryBigEndian => 2,
+    };
+    let mut out = CACHE_MAGIC.to_vec();
+    let flags = buffers.center_f64.is_some() as u32 | (!buffers.scales.is_empty() as u32) << 1;
+    for v in [CACHE_FORMAT_VERSION, buffers.count, buffers.sh_degree, format, flags] {
+        out.extend_from_slice(&v.to_le_bytes());
+    }
+    let floats = buffers.bbox_min.iter().chain(&buffers.bbox_max);
+    for v in floats.chain(&buffers.center[..]).chain(&buffers.covariance[..]) {
+        out.extend_from_slice(&v.to_le_bytes());
+    }
+    buffers.rgba.iter().for_each(|v| out.extend_from_slice(&v.to_le_bytes()));
+    if let Some(c) = &buffers.center_f64 {
+        c.iter().for_each(|v| out.extend_from_slice(&v.to_le_bytes()));
+    }
+    let tail = buffers.sh.iter().chain(&buffers.scales[..]).chain(&buffers.quats[..]);
+    tail.for_each(|v| out.extend_from_slice(&v.to_le_bytes()));
+    out
+}
+
+/// Restores buffers written by [`serialize_buffers`]; the bounding sphere isn't stored and is
+/// refit from the centers. Values are decoded from little-endian, so big-endian hosts
+/// byte-swap them. Fails on a wrong magic, another format version, or a size that doesn't
+/// match the header.
+pub fn deserialize_buffers(bytes: &[u8]) -> Result<SplatPlyBuffersCore, PlyError> {
+    fn take<'a, const N: usize>(bytes: &'a [u8], pos: &mut usize, n: usize) -> Result<&'a [u8], PlyError> {
+        let len = n.checked_mul(N).ok_or_else(|| PlyError::msg(PlyErrorKind::InvalidData, "cache: size overflows"))?;
+        let end = pos.checked_add(len).filter(|&end| end <= bytes.len());
+        let end = end.ok_or_else(|| PlyError::msg(PlyErrorKind::Truncated, "cache: truncated"))?;
+        let out = &bytes[*pos..end];
+        *pos = end;
+        Ok(out)
+    }
+    fn f32s(bytes: &[u8], pos: &mut usize, n: usize) -> Result<Box<[f32]>, PlyError> {
+        let raw = take::<4>(bytes, pos, n)?;
+        Ok(raw.chunks_exact(4).map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]])).collect())
+    }
+    fn u32s(bytes: &[u8], pos: &mut usize, n: usize) -> Result<Box<[u32]>, PlyError> {
+        let raw = take::<4>(bytes, pos, n)?;
+        Ok(raw.chunks_exact(4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]])).collect())
+    }
+
+    if !bytes.starts_with(&CACHE_MAGIC) {
+        return Err(PlyError::msg(PlyErrorKind::InvalidData, "cache: bad magic bytes, not a splat cache"));
+    }
+    let mut pos = CACHE_MAGIC.len();
+    let header = u32s(bytes, &mut pos, 5)?;
+    let [version, count, sh_degree, format, flags] = [header[0], header[1], header[2], header[3], header[4]];
+    if version != CACHE_FORMAT_VERSION {
+        return Err(PlyError::MsgOwned(
+            PlyErrorKind::InvalidData,
+            format!("cache: format version {version}, expected {CACHE_FORMAT_VERSION}"),
+        ));
+    }
+    let format = match format {
+        0 => PlyFormat::Ascii,
+        1 => PlyFormat::BinaryLittleEndian,
+        2 => PlyFormat::BinaryBigEndian,
+        _ => return Err(PlyError::MsgOwned(PlyErrorKind::InvalidData, format!("cache: unknown format tag {format}"))),
+    };
+    if sh_degree > 3 {
+        return Err(PlyError::MsgOwned(PlyErrorKind::InvalidData, format!("cache: SH degree {sh_degree} out of range")));
+    }
+    let n = count as usize;
+    // `n * k` values, checked so a hostile count can't wrap on 32-bit targets.
+    let values = |n: usize, k: usize| {
+        n.checked_mul(k).ok_or_else(|| PlyError::msg(PlyErrorKind::InvalidData, "cache: size overflows"))
+    };
+    let bbox = f32s(bytes, &mut pos, 6)?;
+    let center = f32s(bytes, &mut pos, values(n, 3)?)?;
+    let covariance = f32s(bytes, &mut pos, values(n, 6)?)?;
+    let rgba = u32s(bytes, &mut pos, n)?;
+    let center_f64 = if is_bit_set_u32(flags, 0) {
+        let raw = take::<8>(bytes, &mut pos, values(n, 3)?)?;
+        Some(raw.chunks_exact(8).map(|b| f64::from_le_bytes(b.try_into().unwrap())).collect())
+    } else {
+        None
+    };
+    let sh = f32s(bytes, &mut pos, values(n, sh_rest_len(sh_degree))?)?;
+    let raw = if is_bit_set_u32(flags, 1) { n } else { 0 };
+    let scales = f32s(bytes, &mut pos, values(raw, 3)?)?;
+    let quats = f32s(bytes, &mut pos, values(raw, 4)?)?;
+    if pos != bytes.len() {
+        return Err(PlyError::msg(PlyErrorKind::InvalidData, "cache: trailing bytes after the last buffer"));
+    }
+    let mut out = SplatPlyBuffersCore {
+        count,
+        format,
+        center,
+        covariance,
+        rgba,
+        bbox_min: [bbox[0], bbox[1], bbox[2]],
+        bbox_max: [bbox[3], bbox[4], bbox[5]],
+        bsphere_center: [0.0; 3],
+        bsphere_radius: 0.0,
+        centroid: [0.0; 3],
+        center_f64,
+        sh,
+        sh_degree,
+        scales,
+        quats,
+        element_name: String::new(),
+        antialiased: false,
+        scale_mode: None,
+    };
+    out.recompute_bounding_sphere();
+    out.recompute_centroid();
+    Ok(out)
+}
@@ -0,0 +1,55 @@
+use wasm_bindgen::prelude::*;
+
+use crate::ply_splat_wasm::{ply_error_to_js, SplatPlyBuffers};
+use crate::ply_splat_core::ParseOptionsCore;
+use crate::splat_io_core::{
+    deserialize_buffers, parse_splat_file_core, parse_splat_ply_base64_core, serialize_buffers,
+    to_webgl_splat_texture, write_splat,
+};
+
+#[wasm_bindgen]
+pub fn parse_splat_file(bytes: &[u8]) -> Result<SplatPlyBuffers, JsValue> {
+    let inner = parse_splat_file_core(bytes).map_err(ply_error_to_js)?;
+    Ok(SplatPlyBuffers { inner })
+}
+
+/// Parses a base64-encoded splat PLY (a `data:...;base64,` URI is accepted) with default options.
+#[wasm_bindgen]
+pub fn parse_splat_ply_base64(s: &str) -> Result<SplatPlyBuffers, JsValue> {
+    let inner = parse_splat_ply_base64_core(s, &ParseOptionsCore::default())
+        .map_err(ply_error_to_js)?;
+    Ok(SplatPlyBuffers { inner })
+}
+
+/// Restores buffers from a cache made by `SplatPlyBuffers.serialize`.
+#[wasm_bindgen]
+pub fn deserialize_splat_cache(bytes: &[u8]) -> Result<SplatPlyBuffers, JsValue> {
+    let inner = deserialize_buffers(bytes).map_err(ply_error_to_js)?;
+    Ok(SplatPlyBuffers { inner })
+}
+
+#[wasm_bindgen]
+impl SplatPlyBuffers {
+    /// All buffers as a little-endian cache blob for `deserialize_splat_cache`, e.g. to keep
+    /// in IndexedDB instead of re-parsing the PLY.
+    pub fn serialize(&self) -> js_sys::Uint8Array {
+        js_sys::Uint8Array::from(&serialize_buffers(&self.inner)[..])
+    }
+
+    /// The splats as an antimatter15 `.splat` file (32 bytes per splat, no SH).
+    #[wasm_bindgen(js_name = toSplatFile)]
+    pub fn to_splat_file(&self) -> js_sys::Uint8Array {
+        js_sys::Uint8Array::from(&write_splat(&self.inner)[..])
+    }
+
+    /// Data texture for the antimatter15 WebGL viewer as `[texels (Uint32Array), width, height]`.
+    #[wasm_bindgen(js_name = toWebglSplatTexture)]
+    pub fn to_webgl_splat_texture(&self) -> js_sys::Array {
+        let (texels, width, height) = to_webgl_splat_texture(&self.inner);
+        let arr = js_sys::Array::new();
+        arr.push(&js_sys::Uint32Array::from(&texels[..]));
+        arr.push(&JsValue::from(width));
+        arr.push(&JsValue::from(height));
+        arr
+    }
+}
@@ -0,0 +1,995 @@
+use std::collections::HashMap;
+
+use crate::bitops_core::{f16_bits_to_f32, f32_to_f16_bits, morton_encode_3d};
+use crate::ply_splat_core::{rgba_to_u32, sh_rest_len, PlyError, PlyErrorKind, SplatPlyBuffersCore};
+
+/// Opacity in [0, 1] from a packed rgba (alpha in the high byte).
+pub fn alpha01(rgba: u32) -> f32 {
+    (rgba >> 24) as f32 / 255.0
+}
+
+/// Separates alpha from the packed colors: `(rgb, opacity)` where `rgb` keeps each splat's
+/// color with alpha forced to 255 and `opacity` is the original alpha in [0, 1].
+pub fn split_opacity(buffers: &SplatPlyBuffersCore) -> (Box<[u32]>, Box<[f32]>) {
+    let rgb = buffers.rgba.iter().map(|&c| c | 0xFF00_0000).collect();
+    let opacity = buffers.rgba.iter().map(|&c| alpha01(c)).collect();
+    (rgb, opacity)
+}
+
+/// A single representative color for the scene: the opacity-weighted mean of the splat
+/// colors (each splat's rgb weighted by its alpha), returned opaque. A mean rather than a
+/// histogram mode, so a scene split between two colors yields their blend. Transparent black
+/// (0) when no splat has any opacity.
+pub fn dominant_color(buffers: &SplatPlyBuffersCore) -> u32 {
+    let mut sum = [0.0f64; 3];
+    let mut weight = 0.0f64;
+    for &c in buffers.rgba.iter() {
+        let a = alpha01(c) as f64;
+        for (k, s) in sum.iter_mut().enumerate() {
+            *s += a * ((c >> (8 * k)) & 255) as f64;
+        }
+        weight += a;
+    }
+    if weight == 0.0 {
+        return 0;
+    }
+    let [r, g, b] = sum.map(|s| (s / weight).round() as u32);
+    rgba_to_u32(r, g, b, 255)
+}
+
+/// Per-axis spread of the splats: for each world axis, the min / max / mean of the standard
+/// deviations `sqrt(covariance diagonal)`. All zeros for an empty scene.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ScaleStats {
+    pub min: [f32; 3],
+    pub max: [f32; 3],
+    pub mean: [f32; 3],
+}
+
+/// [`ScaleStats`] over every splat.
+pub fn scale_stats(buffers: &SplatPlyBuffersCore) -> ScaleStats {
+    let n = buffers.covariance.len() / 6;
+    if n == 0 {
+        return ScaleStats::default();
+    }
+    let mut min = [f32::INFINITY; 3];
+    let mut max = [0.0f32; 3];
+    let mut sum = [0.0f64; 3];
+    for c in buffers.covariance.chunks_exact(6) {
+        for (k, d) in [c[0], c[3], c[5]].into_iter().enumerate() {
+            let s = d.max(0.0).sqrt();
+            min[k] = min[k].min(s);
+            max[k] = max[k].max(s);
+            sum[k] += s as f64;
+        }
+    }
+    ScaleStats {
+        min,
+        max,
+        mean: sum.map(|s| (s / n as f64) as f32),
+    }
+}
+
+/// Median over splats of the mean standard deviation along the principal axes
+/// (mean of `sqrt` of the covariance eigenvalues). Robust to a few giant outliers, unlike the
+/// mean. Uses a selection instead of a full sort; even counts average the two middle values.
+/// Returns 0 for an empty scene.
+pub fn median_scale(buffers: &SplatPlyBuffersCore) -> f32 {
+    let mut scales: Vec<f32> = buffers
+        .covariance
+        .chunks_exact(6)
+        .map(|c| covariance_eigen(c).0.iter().map(|v| v.max(0.0).sqrt()).sum::<f32>() / 3.0)
+        .collect();
+    let n = scales.len();
+    if n == 0 {
+        return 0.0;
+    }
+    let mid = n / 2;
+    let (lower, &mut upper, _) = scales.select_nth_unstable_by(mid, f32::total_cmp);
+    if n % 2 == 1 {
+        return upper;
+    }
+    let below = lower.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    (below + upper) * 0.5
+}
+
+/// Determinant of a packed symmetric covariance `[m11, m12, m13, m22, m23, m33]`.
+pub fn covariance_det(c: &[f32]) -> f32 {
+    c[0] * (c[3] * c[5] - c[4] * c[4]) - c[1] * (c[1] * c[5] - c[4] * c[2])
+        + c[2] * (c[1] * c[4] - c[3] * c[2])
+}
+
+/// How much a splat contributes to the image: `opacity * sqrt(det(cov))`.
+/// `sqrt(det)` is proportional to the ellipsoid volume, so faint or tiny splats score low.
+pub fn splat_importance(cov: &[f32], rgba: u32) -> f32 {
+    alpha01(rgba) * covariance_det(cov).max(0.0).sqrt()
+}
+
+/// [`splat_importance`] for every splat.
+pub fn importance_scores(buffers: &SplatPlyBuffersCore) -> Box<[f32]> {
+    buffers
+        .covariance
+        .chunks_exact(6)
+        .zip(buffers.rgba.iter())
+        .map(|(c, &rgba)| splat_importance(c, rgba))
+        .collect()
+}
+
+/// Keeps the `max` most important splats (by [`splat_importance`]), preserving their
+/// original relative order, and recomputes the bounds. No-op if the scene already fits.
+pub fn keep_most_important(buffers: &mut SplatPlyBuffersCore, max: usize) {
+    let n = buffers.count as usize;
+    if n <= max {
+        return;
+    }
+    let scores = importance_scores(buffers);
+    let mut order: Vec<u32> = (0..n as u32).collect();
+    if max > 0 {
+        order.select_nth_unstable_by(max - 1, |&a, &b| {
+            scores[b as usize].total_cmp(&scores[a as usize])
+        });
+    }
+    order.truncate(max);
+    order.sort_unstable();
+    *buffers = buffers.select(&order);
+}
+
+/// Drops splats whose covariance encloses (almost) no volume: those with
+/// `sqrt(det(cov)) < min_volume` (`sqrt(det)` is proportional to the ellipsoid volume), plus any
+/// with a non-finite determinant. Survivors keep their order and the bounds are recomputed.
+/// Returns how many splats were removed.
+pub fn remove_degenerate(buffers: &mut SplatPlyBuffersCore, min_volume: f32) -> usize {
+    let keep: Vec<u32> = buffers
+        .covariance
+        .chunks_exact(6)
+        .enumerate()
+        .filter(|(_, c)| {
+            let det = covariance_det(c);
+            det.is_finite() && det.max(0.0).sqrt() >= min_volume
+        })
+        .map(|(i, _)| i as u32)
+        .collect();
+    let removed = buffers.count as usize - keep.len();
+    if removed > 0 {
+        *buffers = buffers.select(&keep);
+    }
+    removed
+}
+
+/// Eigen-decomposition of a packed symmetric covariance `[m11, m12, m13, m22, m23, m33]`
+/// (cyclic Jacobi). Returns eigenvalues in ascending order and the matching unit eigenvectors
+/// (`vectors[k]` belongs to `values[k]`), forming a right-handed basis.
+pub fn covariance_eigen(c: &[f32]) -> ([f32; 3], [[f32; 3]; 3]) {
+    let mut a = [
+        [c[0] as f64, c[1] as f64, c[2] as f64],
+        [c[1] as f64, c[3] as f64, c[4] as f64],
+        [c[2] as f64, c[4] as f64, c[5] as f64],
+    ];
+    // Columns of `v` accumulate the rotations, ending up as the eigenvectors.
+    let mut v = [[1.0f64, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+    for _sweep in 0..32 {
+        let off = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+        let scale = a[0][0] * a[0][0] + a[1][1] * a[1][1] + a[2][2] * a[2][2];
+        if off <= 1e-24 * scale || off == 0.0 {
+            break;
+        }
+        for (p, q) in [(0usize, 1usize), (0, 2), (1, 2)] {
+            if a[p][q] == 0.0 {
+                continue;
+            }
+            let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+            let t = if theta == 0.0 { 1.0 } else { t };
+            let cs = 1.0 / (t * t + 1.0).sqrt();
+            let sn = t * cs;
+            for row in a.iter_mut() {
+                let (akp, akq) = (row[p], row[q]);
+                row[p] = cs * akp - sn * akq;
+                row[q] = sn * akp + cs * akq;
+            }
+            let (rp, rq) = (a[p], a[q]);
+            a[p] = std::array::from_fn(|k| cs * rp[k] - sn * rq[k]);
+            a[q] = std::array::from_fn(|k| sn * rp[k] + cs * rq[k]);
+            for row in v.iter_mut() {
+                let (vkp, vkq) = (row[p], row[q]);
+                row[p] = cs * vkp - sn * vkq;
+                row[q] = sn * vkp + cs * vkq;
+            }
+        }
+    }
+
+    let mut order = [0usize, 1, 2];
+    order.sort_by(|&i, &j| a[i][i].total_cmp(&a[j][j]));
+    let values = order.map(|i| a[i][i] as f32);
+    let mut vectors = order.map(|i| [v[0][i] as f32, v[1][i] as f32, v[2][i] as f32]);
+    if dot3(cross3(vectors[0], vectors[1]), vectors[2]) < 0.0 {
+        vectors[2] = vectors[2].map(|x| -x);
+    }
+    (values, vectors)
+}
+
+/// Approximate surface normal of every splat (3N): the unit eigenvector of its smallest
+/// covariance eigenvalue, i.e. the ellipsoid's shortest axis. The sign is arbitrary.
+pub fn splat_normals(buffers: &SplatPlyBuffersCore) -> Box<[f32]> {
+    buffers
+        .covariance
+        .chunks_exact(6)
+        .flat_map(|c| covariance_eigen(c).1[0])
+        .collect()
+}
+
+/// Inverse of [`covariance_eigen`]: `sum_k values[k] * v_k v_kᵀ`, packed.
+pub fn covariance_from_eigen(values: [f32; 3], vectors: [[f32; 3]; 3]) -> [f32; 6] {
+    let mut out = [0.0f32; 6];
+    for (l, v) in values.iter().zip(vectors.iter()) {
+        out[0] += l * v[0] * v[0];
+        out[1] += l * v[0] * v[1];
+        out[2] += l * v[0] * v[2];
+        out[3] += l * v[1] * v[1];
+        out[4] += l * v[1] * v[2];
+        out[5] += l * v[2] * v[2];
+    }
+    out
+}
+
+/// Packed covariances (6 per splat) as IEEE half floats, for viewers that take a `cov3d`
+/// half buffer. Halves the upload size at the cost of precision: entries keep ~3 significant
+/// digits (relative error ≤ 2⁻¹¹), entries below ~6e-5 (e.g. splats smaller than ~8mm in
+/// scene units) degrade to subnormals with growing relative error and vanish below ~6e-8,
+/// and entries above 65504 become infinity.
+pub fn covariance_to_half(buffers: &SplatPlyBuffersCore) -> Box<[u16]> {
+    buffers.covariance.iter().map(|&c| f32_to_f16_bits(c)).collect()
+}
+
+/// Inverse of [`covariance_to_half`]: widens half-float covariances back to `f32`.
+pub fn covariance_from_half(half: &[u16]) -> Box<[f32]> {
+    half.iter().map(|&h| f16_bits_to_f32(h)).collect()
+}
+
+/// Packed covariances quantized to `i16` with a scale per splat, for compact GPU upload:
+/// `(quantized (6N), scales (N))`. Each splat's entries are divided by its largest absolute
+/// entry (its scale) and mapped to ±32767, so they keep an absolute error of about
+/// `scale / 65534`; small off-diagonal terms of elongated splats lose relative precision.
+/// All-zero covariances get scale 0.
+pub fn covariance_to_i16(buffers: &SplatPlyBuffersCore) -> (Box<[i16]>, Box<[f32]>) {
+    let n = buffers.count as usize;
+    let mut quantized = Vec::with_capacity(n * 6);
+    let mut scales = Vec::with_capacity(n);
+    for cov in buffers.covariance.chunks_exact(6) {
+        let scale = cov.iter().fold(0.0f32, |m, c| m.max(c.abs()));
+        let inv = if scale > 0.0 { i16::MAX as f32 / scale } else { 0.0 };
+        quantized.extend(cov.iter().map(|c| (c * inv).round() as i16));
+        scales.push(scale);
+    }
+    (quantized.into_boxed_slice(), scales.into_boxed_slice())
+}
+
+/// Inverse of [`covariance_to_i16`]: `quantized` (6N) times each splat's scale, back to `f32`.
+pub fn covariance_from_i16(quantized: &[i16], scales: &[f32]) -> Box<[f32]> {
+    quantized
+        .chunks_exact(6)
+        .zip(scales)
+        .flat_map(|(q, &scale)| q.iter().map(move |&v| v as f32 * scale / i16::MAX as f32))
+        .collect()
+}
+
+/// Recovers a `(scale, quat)` pair (linear scales, quaternion as `[x, y, z, w]`) whose
+/// `R S² Rᵀ` reproduces `cov`. Scales come out ascending; the decomposition of a splat is not
+/// unique, so this is generally not the pair the file stored — only the covariance matches.
+pub fn covariance_to_scale_quat(cov: &[f32]) -> ([f32; 3], [f32; 4]) {
+    let (values, v) = covariance_eigen(cov);
+    let scale = values.map(|l| l.max(0.0).sqrt());
+
+    // Rotation matrix with the eigenvectors as columns: r[i][j] = v[j][i].
+    let (r00, r01, r02) = (v[0][0], v[1][0], v[2][0]);
+    let (r10, r11, r12) = (v[0][1], v[1][1], v[2][1]);
+    let (r20, r21, r22) = (v[0][2], v[1][2], v[2][2]);
+    let trace = r00 + r11 + r22;
+    let (x, y, z, w) = if trace > 0.0 {
+        let s = (trace + 1.0).sqrt() * 2.0;
+        ((r21 - r12) / s, (r02 - r20) / s, (r10 - r01) / s, 0.25 * s)
+    } else if r00 > r11 && r00 > r22 {
+        let s = (1.0 + r00 - r11 - r22).sqrt() * 2.0;
+        (0.25 * s, (r01 + r10) / s, (r02 + r20) / s, (r21 - r12) / s)
+    } else if r11 > r22 {
+        let s = (1.0 + r11 - r00 - r22).sqrt() * 2.0;
+        ((r01 + r10) / s, 0.25 * s, (r12 + r21) / s, (r02 - r20) / s)
+    } else {
+        let s = (1.0 + r22 - r00 - r11).sqrt() * 2.0;
+        ((r02 + r20) / s, (r12 + r21) / s, 0.25 * s, (r10 - r01) / s)
+    };
+    (scale, [x, y, z, w])
+}
+
+/// Limits how needle-like splats can be: per splat, eigenvalues smaller than
+/// `largest / max_ratio` are raised to that bound, so `largest / smallest <= max_ratio`.
+///
+/// This changes geometry — thin splats get fatter along their short axes (the long axis and
+/// the orientation are kept). `max_ratio` below 1 is treated as 1, which makes every splat a sphere.
+/// If any splat changes, `scales`/`quats` are cleared.
+pub fn clamp_anisotropy(buffers: &mut SplatPlyBuffersCore, max_ratio: f32) {
+    let max_ratio = max_ratio.max(1.0);
+    let mut changed = false;
+    for cov in buffers.covariance.chunks_exact_mut(6) {
+        let (values, vectors) = covariance_eigen(cov);
+        let floor = values[2] / max_ratio;
+        if values[0] >= floor {
+            continue;
+        }
+        let clamped = values.map(|l| l.max(floor));
+        cov.copy_from_slice(&covariance_from_eigen(clamped, vectors));
+        changed = true;
+    }
+    if changed {
+        buffers.clear_scale_quat();
+    }
+}
+
+/// Ellipsoid extent used for picking, in standard deviations.
+pub const PICK_SIGMA: f32 = 3.0;
+
+/// Finds the nearest splat whose `PICK_SIGMA` ellipsoid is hit by the ray `origin + t * dir`.
+/// Returns the splat index and the hit distance along the normalized `dir`
+/// (`0` if the origin is inside the ellipsoid).
+pub fn pick_ray(buffers: &SplatPlyBuffersCore, origin: [f32; 3], dir: [f32; 3]) -> Option<(u32, f32)> {
+    let len = dot3(dir, dir).sqrt();
+    if len == 0.0 || !len.is_finite() {
+        return None;
+    }
+    let dir = dir.map(|x| x / len);
+
+    let mut best: Option<(u32, f32)> = None;
+    for (i, (c, cov)) in buffers
+        .center
+        .chunks_exact(3)
+        .zip(buffers.covariance.chunks_exact(6))
+        .enumerate()
+    {
+        let rel = [origin[0] - c[0], origin[1] - c[1], origin[2] - c[2]];
+
+        // Cheap reject against the bounding sphere (largest eigenvalue <= trace).
+        let r2 = PICK_SIGMA * PICK_SIGMA * (cov[0] + cov[3] + cov[5]);
+        let along = dot3(rel, dir);
+        if dot3(rel, rel) - along * along > r2 {
+            continue;
+        }
+
+        let (values, vectors) = covariance_eigen(cov);
+        if values[0] <= 0.0 {
+            continue;
+        }
+        // In the eigenbasis scaled by the semi-axes the ellipsoid becomes the unit sphere.
+        let mut o = [0.0f32; 3];
+        let mut d = [0.0f32; 3];
+        for k in 0..3 {
+            let axis = PICK_SIGMA * values[k].sqrt();
+            o[k] = dot3(rel, vectors[k]) / axis;
+            d[k] = dot3(dir, vectors[k]) / axis;
+        }
+        let a = dot3(d, d);
+        let b = 2.0 * dot3(o, d);
+        let cc = dot3(o, o) - 1.0;
+        let disc = b * b - 4.0 * a * cc;
+        if disc < 0.0 {
+            continue;
+        }
+        let sq = disc.sqrt();
+        let t1 = (-b + sq) / (2.0 * a);
+        if t1 < 0.0 {
+            continue;
+        }
+        let t = ((-b - sq) / (2.0 * a)).max(0.0);
+        if best.is_none_or(|(_, bt)| t < bt) {
+            best = Some((i as u32, t));
+        }
+    }
+    best
+}
+
+pub(crate) fn dot3(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+pub(crate) fn cross3(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// View-space depth of each center (`-z` after applying the column-major `view` matrix),
+/// so points in front of a right-handed camera have positive depth.
+pub fn view_depths(centers: &[f32], view: &[f32; 16]) -> Box<[f32]> {
+    centers
+        .chunks_exact(3)
+        .map(|c| -(view[2] * c[0] + view[6] * c[1] + view[10] * c[2] + view[14]))
+        .collect()
+}
+
+/// Euclidean distance from `camera_pos` to each center (3N floats in, N out).
+pub fn camera_distances(centers: &[f32], camera_pos: [f32; 3]) -> Box<[f32]> {
+    centers
+        .chunks_exact(3)
+        .map(|c| {
+            let d = [c[0] - camera_pos[0], c[1] - camera_pos[1], c[2] - camera_pos[2]];
+            dot3(d, d).sqrt()
+        })
+        .collect()
+}
+
+/// Cells per axis of the grid [`morton_order`] quantizes centers into (10 bits).
+const MORTON_GRID: u32 = 1 << 10;
+
+/// Splat indices in ascending Morton (Z-order) code of their centers, so splats close in space
+/// end up close in the buffers. Centers are quantized into a 1024³ grid over the bbox; splats
+/// in the same cell stay in increasing index order.
+pub fn morton_order(buffers: &SplatPlyBuffersCore) -> Box<[u32]> {
+    let (lo, hi) = (buffers.bbox_min, buffers.bbox_max);
+    let scale: [f32; 3] = std::array::from_fn(|k| {
+        let range = hi[k] - lo[k];
+        if range > 0.0 { (MORTON_GRID - 1) as f32 / range } else { 0.0 }
+    });
+    let cell = |k: usize, v: f32| (((v - lo[k]) * scale[k]) as u32).min(MORTON_GRID - 1);
+    let codes: Vec<u32> = buffers
+        .center
+        .chunks_exact(3)
+        .map(|c| morton_encode_3d(cell(0, c[0]), cell(1, c[1]), cell(2, c[2])))
+        .collect();
+    let mut order: Vec<u32> = (0..codes.len() as u32).collect();
+    order.sort_by_key(|&i| codes[i as usize]);
+    order.into_boxed_slice()
+}
+
+/// Number of depth buckets used by [`sort_indices_by_depth`].
+const DEPTH_SORT_BUCKETS: usize = 1 << 16;
+
+/// Splat indices ordered back-to-front (farthest view-space depth first) for alpha blending.
+///
+/// Depths are quantized into 65536 buckets over the scene's depth range and sorted with a
+/// counting sort. The sort is stable: splats in the same bucket stay in increasing index order,
+/// so equal-depth splats keep the same relative order from frame to frame.
+pub fn sort_indices_by_depth(centers: &[f32], view: &[f32; 16]) -> Box<[u32]> {
+    let depths = view_depths(centers, view);
+    let (lo, hi) = depths
+        .iter()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &d| (lo.min(d), hi.max(d)));
+    let range = hi - lo;
+    let scale = if range > 0.0 { (DEPTH_SORT_BUCKETS - 1) as f32 / range } else { 0.0 };
+
+    // Key 0 = farthest.
+    let keys: Vec<usize> = depths
+        .iter()
+        .map(|&d| (((hi - d) * scale) as usize).min(DEPTH_SORT_BUCKETS - 1))
+        .collect();
+
+    let mut starts = vec![0u32; DEPTH_SORT_BUCKETS + 1];
+    for &k in &keys {
+        starts[k + 1] += 1;
+    }
+    for b in 0..DEPTH_SORT_BUCKETS {
+        starts[b + 1] += starts[b];
+    }
+    let mut out = vec![0u32; keys.len()];
+    for (i, &k) in keys.iter().enumerate() {
+        out[starts[k] as usize] = i as u32;
+        starts[k] += 1;
+    }
+    out.into_boxed_slice()
+}
+
+/// Repacks the per-splat SH coefficients of `buffers` ([`SplatPlyBuffersCore::sh`]) into an
+/// RGBA32F texture.
+///
+/// Splat `i` owns the `texels_per_splat` consecutive texels starting at linear texel
+/// `i * texels_per_splat`; texel `t` sits at `(t % width, t / width)`. Its coefficients fill
+/// those texels in order, four floats per texel, and unused lanes are zero. `texels_per_splat`
+/// is raised to the minimum that fits a splat's coefficients. `width` is a power of two at
+/// least `texels_per_splat` and close to square, so when `texels_per_splat` is itself a power
+/// of two no splat straddles a row. Returns `(texels, width, height)`, or `InvalidArgument` if
+/// the texture's float count doesn't fit in `u32`.
+pub fn sh_to_texture_layout(
+    buffers: &SplatPlyBuffersCore,
+    texels_per_splat: usize,
+) -> Result<(Box<[f32]>, u32, u32), PlyError> {
+    let count = buffers.count as usize;
+    if count == 0 {
+        return Ok((Box::new([]), 1, 0));
+    }
+    let per_splat = sh_rest_len(buffers.sh_degree);
+    let tps = texels_per_splat.max(per_splat.div_ceil(4)).max(1);
+    let too_large = || {
+        PlyError::MsgOwned(
+            PlyErrorKind::InvalidArgument,
+            format!("SH texture: {count} splats of {tps} texels don't fit in a texture"),
+        )
+    };
+    let total = (count as u64).checked_mul(tps as u64).ok_or_else(too_large)?;
+    let side = (total as f64).sqrt().ceil() as u64;
+    let width = side.max(tps as u64).checked_next_power_of_two().ok_or_else(too_large)?;
+    let height = total.div_ceil(width);
+    let (width, height) = match (u32::try_from(width), u32::try_from(height)) {
+        (Ok(w), Ok(h)) => (w, h),
+        _ => return Err(too_large()),
+    };
+    let floats = (width as u64 * height as u64)
+        .checked_mul(4)
+        .and_then(|n| u32::try_from(n).ok())
+        .ok_or_else(too_large)? as usize;
+
+    let mut out = vec![0.0f32; floats];
+    if per_splat > 0 {
+        for (i, coeffs) in buffers.sh.chunks_exact(per_splat).take(count).enumerate() {
+            let base = i * tps * 4;
+            out[base..base + per_splat].copy_from_slice(coeffs);
+        }
+    }
+    Ok((out.into_boxed_slice(), width, height))
+}
+
+/// Colormaps for debug visualizations. Sampled piecewise-linearly from 9 stops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colormap {
+    Grayscale,
+    Viridis,
+    Turbo,
+}
+
+const VIRIDIS_STOPS: [[u8; 3]; 9] = [
+    [68, 1, 84],
+    [71, 44, 122],
+    [59, 81, 139],
+    [44, 113, 142],
+    [33, 144, 141],
+    [39, 173, 129],
+    [92, 200, 99],
+    [170, 220, 50],
+    [253, 231, 37],
+];
+
+const TURBO_STOPS: [[u8; 3]; 9] = [
+    [48, 18, 59],
+    [70, 107, 227],
+    [40, 187, 236],
+    [49, 242, 153],
+    [162, 252, 60],
+    [237, 208, 58],
+    [251, 128, 34],
+    [208, 47, 5],
+    [122, 4, 3],
+];
+
+impl Colormap {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "grayscale" | "gray" => Some(Colormap::Grayscale),
+            "viridis" => Some(Colormap::Viridis),
+            "turbo" => Some(Colormap::Turbo),
+            _ => None,
+        }
+    }
+
+    /// Color at `t` in [0, 1] (clamped).
+    pub fn sample(&self, t: f32) -> [u8; 3] {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let stops = match self {
+            Colormap::Grayscale => {
+                let v = (t * 255.0).round() as u8;
+                return [v, v, v];
+            }
+            Colormap::Viridis => &VIRIDIS_STOPS,
+            Colormap::Turbo => &TURBO_STOPS,
+        };
+        let x = t * (stops.len() - 1) as f32;
+        let i = (x.floor() as usize).min(stops.len() - 2);
+        let f = x - i as f32;
+        let (a, b) = (stops[i], stops[i + 1]);
+        std::array::from_fn(|k| (a[k] as f32 + (b[k] as f32 - a[k] as f32) * f).round() as u8)
+    }
+}
+
+/// Recolors every splat by its view-space depth, normalized over the scene's depth range:
+/// the nearest splat gets `colormap.sample(0)`, the farthest `colormap.sample(1)`.
+/// Alpha is preserved.
+pub fn colorize_by_depth(buffers: &mut SplatPlyBuffersCore, view: &[f32; 16], colormap: Colormap) {
+    let depths = view_depths(&buffers.center, view);
+    let (lo, hi) = depths
+        .iter()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &d| (lo.min(d), hi.max(d)));
+    let range = hi - lo;
+    for (rgba, &d) in buffers.rgba.iter_mut().zip(depths.iter()) {
+        let t = if range > 0.0 { (d - lo) / range } else { 0.0 };
+        let [r, g, b] = colormap.sample(t);
+        *rgba = rgba_to_u32(r as u32, g as u32, b as u32, *rgba >> 24);
+    }
+}
+
+/// Projects a splat through the column-major `view_proj` onto a `viewport` of `[width, height]`
+/// pixels. Returns the pixel-space center and the 2D covariance `[xx, xy, yy]` in pixels²
+/// (first-order approximation of the perspective divide), or `None` if the center is behind
+/// the camera.
+pub fn project_covariance_2d(
+    center: [f32; 3],
+    cov: &[f32],
+    view_proj: &[f32; 16],
+    viewport: [f32; 2],
+) -> Option<([f32; 2], [f32; 3])> {
+    let m = view_proj;
+    let row = |r: usize| [m[r], m[4 + r], m[8 + r]];
+    let clip = |r: usize| dot3(row(r), center) + m[12 + r];
+    let (x, y, w) = (clip(0), clip(1), clip(3));
+    if w <= 0.0 {
+        return None;
+    }
+
+    // d(screen)/d(world): ndc = clip.xy / w, screen = (ndc * 0.5 + 0.5) * viewport.
+    let jac = |r: usize, v: f32, size: f32| -> [f32; 3] {
+        let (a, b) = (row(r), row(3));
+        std::array::from_fn(|k| 0.5 * size * (a[k] * w - v * b[k]) / (w * w))
+    };
+    let j0 = jac(0, x, viewport[0]);
+    let j1 = jac(1, y, viewport[1]);
+
+    let sigma = |v: [f32; 3]| -> [f32; 3] {
+        [
+            cov[0] * v[0] + cov[1] * v[1] + cov[2] * v[2],
+            cov[1] * v[0] + cov[3] * v[1] + cov[4] * v[2],
+            cov[2] * v[0] + cov[4] * v[1] + cov[5] * v[2],
+        ]
+    };
+    let (s0, s1) = (sigma(j0), sigma(j1));
+    let screen = [(x / w * 0.5 + 0.5) * viewport[0], (y / w * 0.5 + 0.5) * viewport[1]];
+    Some((screen, [dot3(j0, s0), dot3(j0, s1), dot3(j1, s1)]))
+}
+
+/// Coordinate axis, used as the viewing direction of an orthographic projection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+impl Axis {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "x" | "X" => Some(Axis::X),
+            "y" | "Y" => Some(Axis::Y),
+            "z" | "Z" => Some(Axis::Z),
+            _ => None,
+        }
+    }
+
+    /// The two remaining axes `(u, v)` spanning the plane perpendicular to this one,
+    /// in increasing order: X → (y, z), Y → (x, z), Z → (x, y).
+    pub fn plane(&self) -> (usize, usize) {
+        match self {
+            Axis::X => (1, 2),
+            Axis::Y => (0, 2),
+            Axis::Z => (0, 1),
+        }
+    }
+}
+
+/// 2D covariances `[uu, uv, vv]` (3N) for an orthographic view along `axis`, e.g. a top-down
+/// minimap. Orthographic projection onto the plane perpendicular to `axis` just drops that
+/// coordinate, and the projection of a Gaussian is its marginal, so each result is the 2x2
+/// block of the 3D covariance for the plane axes `(u, v)` of [`Axis::plane`]; no
+/// approximation is involved, unlike [`project_covariance_2d`].
+pub fn covariance_to_2d_ortho(buffers: &SplatPlyBuffersCore, axis: Axis) -> Box<[f32]> {
+    // Index into the packed upper triangle [xx, xy, xz, yy, yz, zz] for i <= j.
+    const SYM: [[usize; 3]; 3] = [[0, 1, 2], [1, 3, 4], [2, 4, 5]];
+    let (u, v) = axis.plane();
+    buffers
+        .covariance
+        .chunks_exact(6)
+        .flat_map(|c| [c[SYM[u][u]], c[SYM[u][v]], c[SYM[v][v]]])
+        .collect()
+}
+
+/// Ellipse extent counted as a splat's screen footprint, in standard deviations.
+const FOOTPRINT_SIGMA: f32 = 3.0;
+
+/// Average overdraw: the summed screen area of every splat's `3σ` ellipse divided by the
+/// viewport area. Splats behind the camera are skipped; footprints are not clipped to the
+/// viewport, so off-screen splats in front of the camera still count.
+pub fn estimate_overdraw(buffers: &SplatPlyBuffersCore, view_proj: &[f32; 16], viewport: [f32; 2]) -> f64 {
+    let viewport_area = viewport[0] as f64 * viewport[1] as f64;
+    if viewport_area <= 0.0 {
+        return 0.0;
+    }
+    let mut area = 0.0f64;
+    for (c, cov) in buffers.center.chunks_exact(3).zip(buffers.covariance.chunks_exact(6)) {
+        if let Some((_, [xx, xy, yy])) = project_covariance_2d([c[0], c[1], c[2]], cov, view_proj, viewport) {
+            let det = (xx * yy - xy * xy).max(0.0) as f64;
+            area += std::f64::consts::PI * (FOOTPRINT_SIGMA * FOOTPRINT_SIGMA) as f64 * det.sqrt();
+        }
+    }
+    area / viewport_area
+}
+
+/// Combines two scenes, collapsing each splat of `a` with the nearest not-yet-fused splat of
+/// `b` within `merge_radius` (centers, covariances and colors are averaged). The result holds
+/// `a`'s splats (fused or not) in order, then `b`'s unfused ones. A non-positive radius just
+/// concatenates. Neighbors are found with a uniform grid of `merge_radius` cells, so the cost
+/// is linear for reasonably spread scenes. `center_f64` is kept only if both scenes have it,
+/// and higher-order SH only if both have the same SH degree; `scales`/`quats` are dropped.
+pub fn fuse(a: &SplatPlyBuffersCore, b: &SplatPlyBuffersCore, merge_radius: f32) -> SplatPlyBuffersCore {
+    let na = a.count as usize;
+    let nb = b.count as usize;
+    let cell_of = |c: &[f32]| -> [i64; 3] { std::array::from_fn(|k| (c[k] / merge_radius).floor() as i64) };
+
+    let mut grid: HashMap<[i64; 3], Vec<usize>> = HashMap::new();
+    if merge_radius > 0.0 {
+        for (j, c) in b.center.chunks_exact(3).enumerate() {
+            grid.entry(cell_of(c)).or_default().push(j);
+        }
+    }
+
+    let mut used = vec![false; nb];
+    let mut partner: Vec<Option<usize>> = vec![None; na];
+    let r2 = merge_radius * merge_radius;
+    if merge_radius > 0.0 {
+        for (i, ca) in a.center.chunks_exact(3).enumerate() {
+            let [x, y, z] = cell_of(ca);
+            let mut best: Option<(usize, f32)> = None;
+            for dx in -1..=1 {
+                for dy in -1..=1 {
+                    for dz in -1..=1 {
+                        let Some(cands) = grid.get(&[x + dx, y + dy, z + dz]) else {
+                            continue;
+                        };
+                        for &j in cands {
+                            if used[j] {
+                                continue;
+                            }
+                            let cb = &b.center[j * 3..j * 3 + 3];
+                            let d = [ca[0] - cb[0], ca[1] - cb[1], ca[2] - cb[2]];
+                            let d2 = dot3(d, d);
+                            if d2 <= r2 && best.is_none_or(|(_, bd)| d2 < bd) {
+                                best = Some((j, d2));
+                            }
+                        }
+                    }
+                }
+            }
+            if let Some((j, _)) = best {
+                used[j] = true;
+                partner[i] = Some(j);
+            }
+        }
+    }
+
+    let keep_f64 = a.center_f64.is_some() && b.center_f64.is_some();
+    let sh_degree = if a.sh_degree == b.sh_degree { a.sh_degree } else { 0 };
+    let sh_len = sh_rest_len(sh_degree);
+    let mut out = SplatPlyBuffersCore::empty();
+    out.format = a.format;
+    out.element_name = a.element_name.clone();
+    out.antialiased = a.antialiased && b.antialiased;
+    out.scale_mode = if a.scale_mode == b.scale_mode { a.scale_mode } else { None };
+    let mut sh: Vec<f32> = Vec::with_capacity((na + nb) * sh_len);
+    let mut center: Vec<f32> = Vec::with_capacity((na + nb) * 3);
+    let mut covariance: Vec<f32> = Vec::with_capacity((na + nb) * 6);
+    let mut rgba: Vec<u32> = Vec::with_capacity(na + nb);
+    let mut center_f64: Vec<f64> = Vec::new();
+
+    for i in 0..na {
+        let (ca, cova) = (&a.center[i * 3..i * 3 + 3], &a.covariance[i * 6..i * 6 + 6]);
+        match partner[i] {
+            Some(j) => {
+                let (cb, covb) = (&b.center[j * 3..j * 3 + 3], &b.covariance[j * 6..j * 6 + 6]);
+                center.extend(ca.iter().zip(cb).map(|(p, q)| (p + q) * 0.5));
+                covariance.extend(cova.iter().zip(covb).map(|(p, q)| (p + q) * 0.5));
+                rgba.push(average_rgba(a.rgba[i], b.rgba[j]));
+                if let (true, Some(fa), Some(fb)) = (keep_f64, &a.center_f64, &b.center_f64) {
+                    center_f64.extend((0..3).map(|k| (fa[i * 3 + k] + fb[j * 3 + k]) * 0.5));
+                }
+                let (sa, sb) = (&a.sh[i * sh_len..(i + 1) * sh_len], &b.sh[j * sh_len..(j + 1) * sh_len]);
+                sh.extend(sa.iter().zip(sb).map(|(p, q)| (p + q) * 0.5));
+            }
+            None => {
+                center.extend_from_slice(ca);
+                covariance.extend_from_slice(cova);
+                rgba.push(a.rgba[i]);
+                if let (true, Some(fa)) = (keep_f64, &a.center_f64) {
+                    center_f64.extend_from_slice(&fa[i * 3..i * 3 + 3]);
+                }
+                sh.extend_from_slice(&a.sh[i * sh_len..(i + 1) * sh_len]);
+            }
+        }
+    }
+    for j in (0..nb).filter(|&j| !used[j]) {
+        center.extend_from_slice(&b.center[j * 3..j * 3 + 3]);
+        covariance.extend_from_slice(&b.covariance[j * 6..j * 6 + 6]);
+        rgba.push(b.rgba[j]);
+        if let (true, Some(fb)) = (keep_f64, &b.center_f64) {
+            center_f64.extend_from_slice(&fb[j * 3..j * 3 + 3]);
+        }
+        sh.extend_from_slice(&b.sh[j * sh_len..(j + 1) * sh_len]);
+    }
+
+    out.count = rgba.len() as u32;
+    out.center = center.into_boxed_slice();
+    out.covariance = covariance.into_boxed_slice();
+    out.rgba = rgba.into_boxed_slice();
+    out.center_f64 = keep_f64.then(|| center_f64.into_boxed_slice());
+    out.sh = sh.into_boxed_slice();
+    out.sh_degree = sh_degree;
+    out.recompute_bounds();
+    out
+}
+
+/// Per-channel rounded mean of two packed rgba colors.
+fn average_rgba(p: u32, q: u32) -> u32 {
+    let ch = |c: u32, k: u32| (c >> (8 * k)) & 255;
+    let avg = |k: u32| (ch(p, k) + ch(q, k)).div_ceil(2);
+    rgba_to_u32(avg(0), avg(1), avg(2), avg(3))
+}
+
+/// Collapses the scene into a grid of `voxel_size` cubes, one splat per occupied voxel, in
+/// order of each voxel's first splat. Members are weighted by their alpha byte (at least 1, so
+/// fully transparent voxels still merge): center, rgb, `center_f64` and SH are weighted means,
+/// and the covariance is moment-matched, `Σ w (Σᵢ + dᵢ dᵢᵀ) / Σ w` with `dᵢ` the member's
+/// offset from the merged center, so the merged splat spans its members. Alpha is the members'
+/// maximum. `scales`/`quats` are dropped and the bounds recomputed. One pass over the splats;
+/// fails unless `voxel_size` is positive and finite.
+pub fn voxel_downsample(buffers: &SplatPlyBuffersCore, voxel_size: f32) -> Result<SplatPlyBuffersCore, PlyError> {
+    if !(voxel_size > 0.0 && voxel_size.is_finite()) {
+        return Err(PlyError::MsgOwned(
+            PlyErrorKind::InvalidArgument,
+            format!("voxel downsample: voxel size {voxel_size}, expected a positive finite size"),
+        ));
+    }
+    /// Weighted sums of one voxel's members; offsets are taken from its first member's center
+    /// to keep the second moments precise far from the origin.
+    struct Voxel {
+        origin: [f32; 3],
+        weight: f64,
+        offset: [f64; 3],
+        moment: [f64; 6],
+        rgb: [f64; 3],
+        alpha: u32,
+        center_f64: [f64; 3],
+    }
+
+    let sh_len = sh_rest_len(buffers.sh_degree);
+    let mut index: HashMap<(i32, i32, i32), usize> = HashMap::new();
+    let mut voxels: Vec<Voxel> = Vec::new();
+    let mut sh: Vec<f64> = Vec::new();
+    for i in 0..buffers.count as usize {
+        let c = &buffers.center[i * 3..i * 3 + 3];
+        let cell = |k: usize| (c[k] / voxel_size).floor() as i32;
+        let v = *index.entry((cell(0), cell(1), cell(2))).or_insert_with(|| {
+            voxels.push(Voxel {
+                origin: [c[0], c[1], c[2]],
+                weight: 0.0,
+                offset: [0.0; 3],
+                moment: [0.0; 6],
+                rgb: [0.0; 3],
+                alpha: 0,
+                center_f64: [0.0; 3],
+            });
+            sh.resize(sh.len() + sh_len, 0.0);
+            voxels.len() - 1
+        });
+        let voxel = &mut voxels[v];
+        let rgba = buffers.rgba[i];
+        let w = ((rgba >> 24) as f64).max(1.0);
+        let d: [f64; 3] = std::array::from_fn(|k| (c[k] - voxel.origin[k]) as f64);
+        let cov = &buffers.covariance[i * 6..i * 6 + 6];
+        let dd = [d[0] * d[0], d[0] * d[1], d[0] * d[2], d[1] * d[1], d[1] * d[2], d[2] * d[2]];
+        voxel.weight += w;
+        for (k, (offset, rgb)) in voxel.offset.iter_mut().zip(&mut voxel.rgb).enumerate() {
+            *offset += w * d[k];
+            *rgb += w * ((rgba >> (8 * k)) & 255) as f64;
+        }
+        for (moment, (&cov, dd)) in voxel.moment.iter_mut().zip(cov.iter().zip(dd)) {
+            *moment += w * (cov as f64 + dd);
+        }
+        voxel.alpha = voxel.alpha.max(rgba >> 24);
+        if let Some(c64) = &buffers.center_f64 {
+            for (acc, &x) in voxel.center_f64.iter_mut().zip(&c64[i * 3..i * 3 + 3]) {
+                *acc += w * x;
+            }
+        }
+        for (acc, &x) in sh[v * sh_len..(v + 1) * sh_len].iter_mut().zip(&buffers.sh[i * sh_len..(i + 1) * sh_len]) {
+            *acc += w * x as f64;
+        }
+    }
+
+    let mut out = SplatPlyBuffersCore::empty();
+    out.format = buffers.format;
+    out.element_name = buffers.element_name.clone();
+    out.antialiased = buffers.antialiased;
+    out.scale_mode = buffers.scale_mode;
+    let mut center: Vec<f32> = Vec::with_capacity(voxels.len() * 3);
+    let mut covariance: Vec<f32> = Vec::with_capacity(voxels.len() * 6);
+    let mut rgba: Vec<u32> = Vec::with_capacity(voxels.len());
+    let mut center_f64: Vec<f64> = Vec::new();
+    for (v, voxel) in voxels.iter().enumerate() {
+        let w = voxel.weight;
+        let m = voxel.offset.map(|x| x / w);
+        center.extend((0..3).map(|k| (voxel.origin[k] as f64 + m[k]) as f32));
+        let mm = [m[0] * m[0], m[0] * m[1], m[0] * m[2], m[1] * m[1], m[1] * m[2], m[2] * m[2]];
+        covariance.extend((0..6).map(|k| (voxel.moment[k] / w - mm[k]) as f32));
+        let [r, g, b] = voxel.rgb.map(|x| (x / w).round() as u32);
+        rgba.push(rgba_to_u32(r, g, b, voxel.alpha));
+        if buffers.center_f64.is_some() {
+            center_f64.extend(voxel.center_f64.map(|x| x / w));
+        }
+        for x in &mut sh[v * sh_len..(v + 1) * sh_len] {
+            *x /= w;
+        }
+    }
+
+    out.count = rgba.len() as u32;
+    out.center = center.into_boxed_slice();
+    out.covariance = covariance.into_boxed_slice();
+    out.rgba = rgba.into_boxed_slice();
+    out.center_f64 = buffers.center_f64.as_ref().map(|_| center_f64.into_boxed_slice());
+    out.sh = sh.into_iter().map(|x| x as f32).collect();
+    out.sh_degree = buffers.sh_degree;
+    out.recompute_bounds();
+    Ok(out)
+}
+
+/// Blends two keyframes of the same scene: `a` at `t = 0`, `b` at `t = 1`. Splat `i` of `a` is
+/// paired with splat `i` of `b`, so both must have the same count.
+///
+/// Centers, colors (per channel, alpha included), `centerF64` (when both have it) and SH
+/// (when both have the same degree; otherwise the result has none) are lerped; `scales`/`quats`
+/// are dropped. Covariances are lerped element-wise, which keeps them positive semi-definite but
+/// is only an approximation of rotating the ellipsoid: a splat turning 90° between keyframes
+/// shrinks toward a rounder shape mid-way instead of keeping its extents. Bounds are recomputed.
+pub fn interpolate_scenes(
+    a: &SplatPlyBuffersCore,
+    b: &SplatPlyBuffersCore,
+    t: f32,
+) -> Result<SplatPlyBuffersCore, PlyError> {
+    if a.count != b.count {
+        return Err(PlyError::MsgOwned(
+            PlyErrorKind::InvalidArgument,
+            format!("interpolate: scenes have {} and {} splats, expected equal counts", a.count, b.count),
+        ));
+    }
+    let lerp = |p: &[f32], q: &[f32]| -> Box<[f32]> {
+        p.iter().zip(q).map(|(x, y)| x + (y - x) * t).collect()
+    };
+    let mut out = SplatPlyBuffersCore {
+        count: a.count,
+        format: a.format,
+        center: lerp(&a.center, &b.center),
+        covariance: lerp(&a.covariance, &b.covariance),
+        rgba: a.rgba.iter().zip(b.rgba.iter()).map(|(&p, &q)| lerp_rgba(p, q, t)).collect(),
+        bbox_min: [0.0; 3],
+        bbox_max: [0.0; 3],
+        bsphere_center: [0.0; 3],
+        bsphere_radius: 0.0,
+        centroid: [0.0; 3],
+        center_f64: match (&a.center_f64, &b.center_f64) {
+            (Some(p), Some(q)) => {
+                let t = t as f64;
+                Some(p.iter().zip(q.iter()).map(|(x, y)| x + (y - x) * t).collect())
+            }
+            _ => None,
+        },
+        sh: if a.sh_degree == b.sh_degree { lerp(&a.sh, &b.sh) } else { Box::new([]) },
+        sh_degree: if a.sh_degree == b.sh_degree { a.sh_degree } else { 0 },
+        scales: Box::new([]),
+        quats: Box::new([]),
+        element_name: a.element_name.clone(),
+        antialiased: a.antialiased && b.antialiased,
+        scale_mode: if a.scale_mode == b.scale_mode { a.scale_mode } else { None },
+    };
+    out.recompute_bounds();
+    Ok(out)
+}
+
+/// Per-channel rounded lerp of two packed rgba colors, clamped to [0, 255].
+fn lerp_rgba(p: u32, q: u32, t: f32) -> u32 {
+    let ch = |c: u32, k: u32| ((c >> (8 * k)) & 255) as f32;
+    let mix = |k: u32| (ch(p, k) + (ch(q, k) - ch(p, k)) * t).round().clamp(0.0, 255.0) as u32;
+    rgba_to_u32(mix(0), mix(1), mix(2), mix(3))
+}
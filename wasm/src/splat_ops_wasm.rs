@@ -0,0 +1,160 @@
+use wasm_bindgen::prelude::*;
+
+use crate::ply_splat_wasm::{ply_error_to_js, SplatPlyBuffers};
+use crate::splat_ops_core;
+
+fn mat4_arg(v: &[f32], name: &str) -> Result<[f32; 16], JsValue> {
+    v.try_into()
+        .map_err(|_| JsValue::from_str(&format!("{name}: expected 16 floats, got {}", v.len())))
+}
+
+fn vec3_arg(v: &[f32], name: &str) -> Result<[f32; 3], JsValue> {
+    v.try_into()
+        .map_err(|_| JsValue::from_str(&format!("{name}: expected 3 floats, got {}", v.len())))
+}
+
+#[wasm_bindgen]
+impl SplatPlyBuffers {
+    /// Nearest splat hit by the ray, as `[index, distance]`, or `undefined` on a miss.
+    #[wasm_bindgen(js_name = pickRay)]
+    pub fn pick_ray(&self, origin: &[f32], dir: &[f32]) -> Result<Option<js_sys::Array>, JsValue> {
+        let origin = vec3_arg(origin, "origin")?;
+        let dir = vec3_arg(dir, "dir")?;
+        Ok(splat_ops_core::pick_ray(&self.inner, origin, dir).map(|(i, t)| {
+            let arr = js_sys::Array::new();
+            arr.push(&JsValue::from(i));
+            arr.push(&JsValue::from(t));
+            arr
+        }))
+    }
+
+    /// Recolors splats by view-space depth. `colormap` is "grayscale", "viridis" or "turbo".
+    #[wasm_bindgen(js_name = colorizeByDepth)]
+    pub fn colorize_by_depth(&mut self, view: &[f32], colormap: &str) -> Result<(), JsValue> {
+        let view = mat4_arg(view, "view")?;
+        let colormap = splat_ops_core::Colormap::parse(colormap)
+            .ok_or_else(|| JsValue::from_str(&format!("unknown colormap \"{colormap}\"")))?;
+        splat_ops_core::colorize_by_depth(&mut self.inner, &view, colormap);
+        Ok(())
+    }
+
+    /// Summed `3σ` screen area of all splats divided by the viewport area.
+    #[wasm_bindgen(js_name = estimateOverdraw)]
+    pub fn estimate_overdraw(&self, view_proj: &[f32], width: f32, height: f32) -> Result<f64, JsValue> {
+        let view_proj = mat4_arg(view_proj, "viewProj")?;
+        Ok(splat_ops_core::estimate_overdraw(&self.inner, &view_proj, [width, height]))
+    }
+
+    /// Packed colors with alpha forced to 255 (see `opacity` for the alpha).
+    #[wasm_bindgen(getter, js_name = rgbOpaque)]
+    pub fn rgb_opaque(&self) -> js_sys::Uint32Array {
+        js_sys::Uint32Array::from(&splat_ops_core::split_opacity(&self.inner).0[..])
+    }
+
+    /// Per-splat opacity in [0, 1], taken from the rgba alpha.
+    #[wasm_bindgen(getter)]
+    pub fn opacity(&self) -> js_sys::Float32Array {
+        js_sys::Float32Array::from(&splat_ops_core::split_opacity(&self.inner).1[..])
+    }
+
+    /// Opacity-weighted mean color as packed rgba (alpha 255), or 0 if nothing is visible.
+    #[wasm_bindgen(js_name = dominantColor)]
+    pub fn dominant_color(&self) -> u32 {
+        splat_ops_core::dominant_color(&self.inner)
+    }
+
+    /// Removes splats with `sqrt(det(covariance)) < minVolume`; returns how many were removed.
+    #[wasm_bindgen(js_name = removeDegenerate)]
+    pub fn remove_degenerate(&mut self, min_volume: f32) -> u32 {
+        splat_ops_core::remove_degenerate(&mut self.inner, min_volume) as u32
+    }
+
+    /// Median per-splat scale (mean principal standard deviation).
+    #[wasm_bindgen(js_name = medianScale)]
+    pub fn median_scale(&self) -> f32 {
+        splat_ops_core::median_scale(&self.inner)
+    }
+
+    /// Splat indices back-to-front for the column-major `view`; ties keep index order.
+    #[wasm_bindgen(js_name = sortIndicesByDepth)]
+    pub fn sort_indices_by_depth(&self, view: &[f32]) -> Result<js_sys::Uint32Array, JsValue> {
+        let view = mat4_arg(view, "view")?;
+        let order = splat_ops_core::sort_indices_by_depth(&self.inner.center, &view);
+        Ok(js_sys::Uint32Array::from(&order[..]))
+    }
+
+    /// Covariances as IEEE half-float bits (6N); see `covariance_to_half` for precision.
+    #[wasm_bindgen(getter, js_name = covarianceHalf)]
+    pub fn covariance_half(&self) -> js_sys::Uint16Array {
+        js_sys::Uint16Array::from(&splat_ops_core::covariance_to_half(&self.inner)[..])
+    }
+
+    /// Covariances quantized to `i16` as `{ quantized, scales }`: `quantized` (6N) relative to
+    /// the per-splat `scales` (N), each its splat's largest absolute covariance entry. See
+    /// `covariance_to_i16` for precision.
+    #[wasm_bindgen(js_name = covarianceToI16)]
+    pub fn covariance_to_i16(&self) -> Result<js_sys::Object, JsValue> {
+        let (quantized, scales) = splat_ops_core::covariance_to_i16(&self.inner);
+        let out = js_sys::Object::new();
+        let set = |key: &str, value: &JsValue| js_sys::Reflect::set(&out, &JsValue::from_str(key), value);
+        set("quantized", &js_sys::Int16Array::from(&quantized[..]))?;
+        set("scales", &js_sys::Float32Array::from(&scales[..]))?;
+        Ok(out)
+    }
+
+    /// Distance from `cameraPos` to each splat center.
+    #[wasm_bindgen(js_name = cameraDistances)]
+    pub fn camera_distances(&self, camera_pos: &[f32]) -> Result<js_sys::Float32Array, JsValue> {
+        let camera_pos = vec3_arg(camera_pos, "cameraPos")?;
+        let dist = splat_ops_core::camera_distances(&self.inner.center, camera_pos);
+        Ok(js_sys::Float32Array::from(&dist[..]))
+    }
+
+    /// 2D covariances `[uu, uv, vv]` (3N) for an orthographic view along `axis` ("x", "y" or
+    /// "z"); the plane axes are the remaining two in order, e.g. x and y for "z".
+    #[wasm_bindgen(js_name = covarianceTo2dOrtho)]
+    pub fn covariance_to_2d_ortho(&self, axis: &str) -> Result<js_sys::Float32Array, JsValue> {
+        let axis = splat_ops_core::Axis::parse(axis)
+            .ok_or_else(|| JsValue::from_str(&format!("unknown axis \"{axis}\"")))?;
+        Ok(js_sys::Float32Array::from(&splat_ops_core::covariance_to_2d_ortho(&self.inner, axis)[..]))
+    }
+
+    /// Repacks the SH coefficients into an RGBA32F texture, `texelsPerSplat` (or more) texels
+    /// per splat; returns `[texels (Float32Array), width, height]`. Throws if the texture
+    /// would be too large.
+    #[wasm_bindgen(js_name = shToTextureLayout)]
+    pub fn sh_to_texture_layout(&self, texels_per_splat: u32) -> Result<js_sys::Array, JsValue> {
+        let (texels, width, height) =
+            splat_ops_core::sh_to_texture_layout(&self.inner, texels_per_splat as usize).map_err(ply_error_to_js)?;
+        let arr = js_sys::Array::new();
+        arr.push(&js_sys::Float32Array::from(&texels[..]));
+        arr.push(&JsValue::from(width));
+        arr.push(&JsValue::from(height));
+        Ok(arr)
+    }
+
+    /// Per-splat shortest-axis direction (3N), usable as an approximate normal.
+    #[wasm_bindgen(js_name = splatNormals)]
+    pub fn splat_normals(&self) -> js_sys::Float32Array {
+        js_sys::Float32Array::from(&splat_ops_core::splat_normals(&self.inner)[..])
+    }
+}
+
+/// Widens half-float covariance bits (6N) back to a `Float32Array`.
+#[wasm_bindgen(js_name = covarianceFromHalf)]
+pub fn covariance_from_half(half: &[u16]) -> js_sys::Float32Array {
+    js_sys::Float32Array::from(&splat_ops_core::covariance_from_half(half)[..])
+}
+
+/// Dequantizes the `quantized` (6N) and `scales` (N) of `covarianceToI16` to a `Float32Array`.
+#[wasm_bindgen(js_name = covarianceFromI16)]
+pub fn covariance_from_i16(quantized: &[i16], scales: &[f32]) -> js_sys::Float32Array {
+    js_sys::Float32Array::from(&splat_ops_core::covariance_from_i16(quantized, scales)[..])
+}
+
+/// Blends two equal-count keyframes (`a` at `t = 0`, `b` at `t = 1`) into a new scene.
+#[wasm_bindgen(js_name = interpolateScenes)]
+pub fn interpolate_scenes(a: &SplatPlyBuffers, b: &SplatPlyBuffers, t: f32) -> Result<SplatPlyBuffers, JsValue> {
+    let inner = splat_ops_core::interpolate_scenes(&a.inner, &b.inner, t).map_err(ply_error_to_js)?;
+    Ok(SplatPlyBuffers { inner })
+}